@@ -0,0 +1,183 @@
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::fetcher::FetchError;
+use crate::http::HttpClient;
+use crate::locale::Locale;
+use crate::title;
+
+/// GraphQL-ендпоінт, яким користується фронтенд eolymp.
+pub const DEFAULT_ENDPOINT: &str = "https://api.eolymp.com/graphql";
+
+const PROBLEM_TITLE_QUERY: &str = "query ProblemTitle($number: Int!, $locale: String!) {
+  problem(number: $number) {
+    statement(locale: $locale) {
+      title
+    }
+  }
+}";
+
+#[derive(Deserialize)]
+struct Response {
+    data: Option<Data>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Deserialize)]
+struct Data {
+    problem: Option<ProblemNode>,
+}
+
+#[derive(Deserialize)]
+struct ProblemNode {
+    statement: Option<Statement>,
+}
+
+#[derive(Deserialize)]
+struct Statement {
+    title: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+/// Назва задачі через GraphQL API замість розбору HTML-сторінки.
+pub async fn problem_title(client: &HttpClient, endpoint: &str, problem_id: u32, locale: Locale) -> Result<String, FetchError> {
+    let body = json!({
+        "query": PROBLEM_TITLE_QUERY,
+        "variables": { "number": problem_id, "locale": locale.code() },
+    });
+
+    let text = client.post_json(endpoint, locale.code(), body.to_string()).await?;
+    let response = serde_json::from_str::<Response>(&text)
+        .map_err(|e| FetchError::Api(format!("invalid response: {}", e)))?;
+
+    if !response.errors.is_empty() {
+        let messages = response.errors.into_iter().map(|e| e.message).collect::<Vec<_>>();
+        return Err(FetchError::Api(messages.join("; ")));
+    }
+
+    let problem = response.data
+        .and_then(|data| data.problem)
+        .ok_or(FetchError::NotFound)?;
+
+    let title = problem.statement
+        .and_then(|statement| statement.title)
+        .map(|title| title::normalize_whitespace(&title))
+        .unwrap_or_default();
+
+    if title.is_empty() {
+        return Err(FetchError::NoTitle);
+    }
+    Ok(title)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+
+    use super::*;
+    use crate::http::HttpConfig;
+
+    // Мінімальний HTTP-сервер: відповідає на один запит і повертає його тіло для перевірки
+    fn mock_server(status: &'static str, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}/graphql", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':')
+                    && name.eq_ignore_ascii_case("content-length")
+                {
+                    content_length = value.trim().parse().unwrap();
+                }
+            }
+
+            let mut request = vec![0; content_length];
+            reader.read_exact(&mut request).unwrap();
+            tx.send(String::from_utf8(request).unwrap()).unwrap();
+
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+        });
+
+        (endpoint, rx)
+    }
+
+    fn fetch(endpoint: &str, problem_id: u32) -> Result<String, FetchError> {
+        let client = HttpClient::new(HttpConfig { max_retries: 0, ..HttpConfig::default() }).unwrap();
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(problem_title(&client, endpoint, problem_id, Locale::En))
+    }
+
+    #[test]
+    fn returns_statement_title() {
+        let (endpoint, requests) = mock_server(
+            "200 OK",
+            r#"{"data":{"problem":{"statement":{"title":"  A  plus B "}}}}"#,
+        );
+
+        assert_eq!(fetch(&endpoint, 1), Ok("A plus B".to_string()));
+
+        let request = serde_json::from_str::<serde_json::Value>(&requests.recv().unwrap()).unwrap();
+        assert_eq!(request["variables"]["number"], 1);
+        assert_eq!(request["variables"]["locale"], "en");
+    }
+
+    #[test]
+    fn missing_problem_is_not_found() {
+        let (endpoint, _requests) = mock_server("200 OK", r#"{"data":{"problem":null}}"#);
+        assert_eq!(fetch(&endpoint, 99999), Err(FetchError::NotFound));
+    }
+
+    #[test]
+    fn missing_statement_has_no_title() {
+        let (endpoint, _requests) = mock_server("200 OK", r#"{"data":{"problem":{"statement":null}}}"#);
+        assert_eq!(fetch(&endpoint, 2), Err(FetchError::NoTitle));
+    }
+
+    #[test]
+    fn graphql_errors_are_reported() {
+        let (endpoint, _requests) = mock_server(
+            "200 OK",
+            r#"{"data":null,"errors":[{"message":"rate limited"},{"message":"try later"}]}"#,
+        );
+        assert_eq!(fetch(&endpoint, 3), Err(FetchError::Api("rate limited; try later".to_string())));
+    }
+
+    #[test]
+    fn http_errors_keep_status() {
+        let (endpoint, _requests) = mock_server("503 Service Unavailable", "{}");
+        assert_eq!(fetch(&endpoint, 4), Err(FetchError::Status(503)));
+    }
+
+    #[test]
+    fn malformed_json_is_an_api_error() {
+        let (endpoint, _requests) = mock_server("200 OK", "<html></html>");
+        assert!(matches!(fetch(&endpoint, 5), Err(FetchError::Api(_))));
+    }
+}
@@ -0,0 +1,277 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_DIR: &str = "cache";
+const BODY_EXTENSION: &str = "html";
+const META_EXTENSION: &str = "toml";
+
+/// Заголовки та час завантаження, потрібні для повторної перевірки сторінки на сервері.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CacheMeta {
+    pub url: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub fetched_at: u64,
+}
+
+pub struct CachedPage {
+    pub meta: CacheMeta,
+    pub body: String,
+}
+
+impl CachedPage {
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        now_secs().saturating_sub(self.meta.fetched_at) < ttl.as_secs()
+    }
+}
+
+/// Кеш сторінок на диску: для кожного URL — тіло сторінки та файл із метаданими.
+pub struct PageCache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+impl PageCache {
+    pub fn new(dir: PathBuf, max_bytes: u64) -> Self {
+        Self {
+            dir,
+            max_bytes,
+        }
+    }
+
+    pub fn get(&self, url: &str) -> Option<CachedPage> {
+        let (body_path, meta_path) = self.paths(url);
+        let meta = toml::from_str::<CacheMeta>(&fs::read_to_string(meta_path).ok()?).ok()?;
+
+        // Різні URL можуть дати однаковий хеш
+        if meta.url != url {
+            return None;
+        }
+
+        let body = fs::read_to_string(body_path).ok()?;
+        Some(CachedPage { meta, body })
+    }
+
+    pub fn put(&self, url: &str, etag: Option<String>, last_modified: Option<String>, body: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        let meta = CacheMeta {
+            url: url.to_string(),
+            etag,
+            last_modified,
+            fetched_at: now_secs(),
+        };
+        let (body_path, _) = self.paths(url);
+        write_atomic(&body_path, body)?;
+        self.write_meta(&meta)?;
+
+        self.evict()
+    }
+
+    /// Сервер відповів 304 — сторінка та сама, лише оновлюємо час перевірки.
+    pub fn touch(&self, url: &str) -> io::Result<()> {
+        let Some(mut page) = self.get(url) else {
+            return Ok(());
+        };
+        page.meta.fetched_at = now_secs();
+        self.write_meta(&page.meta)
+    }
+
+    /// Загальний розмір кешу в байтах.
+    pub fn size(&self) -> u64 {
+        self.entries().iter().map(|entry| entry.bytes).sum()
+    }
+
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    fn write_meta(&self, meta: &CacheMeta) -> io::Result<()> {
+        let (_, meta_path) = self.paths(&meta.url);
+        write_atomic(&meta_path, &toml::to_string(meta).map_err(io::Error::other)?)
+    }
+
+    // Коли кеш перевищує ліміт, першими видаляються сторінки, найдавніше завантажені
+    // чи підтверджені сервером. Відповідь 304 оновлює лише метадані, тому порядок
+    // визначає `fetched_at`, а не час зміни файлу з тілом.
+    fn evict(&self) -> io::Result<()> {
+        let mut entries = self.entries();
+        let mut total = entries.iter().map(|entry| entry.bytes).sum::<u64>();
+        if total <= self.max_bytes {
+            return Ok(());
+        }
+
+        entries.sort_by_key(|entry| entry.fetched_at);
+        for entry in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            // Паралельний запис міг уже видалити цю сторінку — її байти все одно звільнено
+            match fs::remove_file(entry.path.with_extension(BODY_EXTENSION)) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+            let _ = fs::remove_file(entry.path.with_extension(META_EXTENSION));
+            total = total.saturating_sub(entry.bytes);
+        }
+
+        Ok(())
+    }
+
+    fn entries(&self) -> Vec<CacheEntry> {
+        let Ok(dir) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+
+        dir.flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == BODY_EXTENSION))
+            .filter_map(|path| {
+                let metadata = fs::metadata(&path).ok()?;
+                let fetched_at = fs::read_to_string(path.with_extension(META_EXTENSION))
+                    .ok()
+                    .and_then(|text| toml::from_str::<CacheMeta>(&text).ok())
+                    .map(|meta| meta.fetched_at)
+                    // Без метаданих сторінка все одно не читається — хай іде першою
+                    .unwrap_or_default();
+                Some(CacheEntry {
+                    bytes: metadata.len(),
+                    fetched_at,
+                    path,
+                })
+            })
+            .collect()
+    }
+
+    fn paths(&self, url: &str) -> (PathBuf, PathBuf) {
+        let base = self.dir.join(format!("{:016x}", fnv1a(url)));
+        (base.with_extension(BODY_EXTENSION), base.with_extension(META_EXTENSION))
+    }
+}
+
+struct CacheEntry {
+    path: PathBuf,
+    bytes: u64,
+    fetched_at: u64,
+}
+
+// Лічильник робить імена тимчасових файлів унікальними в межах процесу
+static TMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+// Запис через тимчасовий файл, щоб паралельні запити не прочитали половину сторінки.
+// Тіло й метадані однієї сторінки мають спільну основу імені, тож тимчасовий файл
+// іменується за повним ім'ям цілі.
+fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp = path.with_file_name(format!(
+        "{name}.{}.{}.tmp",
+        std::process::id(),
+        TMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&tmp, contents)?;
+    fs::rename(tmp, path)
+}
+
+/// Стабільний між запусками хеш, на відміну від `DefaultHasher`.
+pub fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://eolymp.com/en/problems/1";
+
+    fn temp_cache(name: &str, max_bytes: u64) -> PageCache {
+        let dir = std::env::temp_dir().join(format!("eolymp-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        PageCache::new(dir, max_bytes)
+    }
+
+    // Переносить завантаження сторінки на `age` секунд у минуле
+    fn age(cache: &PageCache, url: &str, age: u64) {
+        let mut meta = cache.get(url).unwrap().meta;
+        meta.fetched_at -= age;
+        cache.write_meta(&meta).unwrap();
+    }
+
+    #[test]
+    fn stored_validators_are_returned() {
+        let cache = temp_cache("validators", 1024);
+        cache.put(URL, Some("\"v1\"".to_string()), Some("Mon, 03 Mar 2025 10:00:00 GMT".to_string()), "<title>A + B</title>").unwrap();
+
+        let page = cache.get(URL).unwrap();
+        assert_eq!(page.body, "<title>A + B</title>");
+        assert_eq!(page.meta.etag.as_deref(), Some("\"v1\""));
+        assert_eq!(page.meta.last_modified.as_deref(), Some("Mon, 03 Mar 2025 10:00:00 GMT"));
+        assert!(cache.get("https://eolymp.com/en/problems/2").is_none());
+
+        // Після запису не лишається тимчасових файлів
+        let files = fs::read_dir(&cache.dir).unwrap().count();
+        assert_eq!(files, 2);
+
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn stale_page_is_refreshed_by_not_modified() {
+        let cache = temp_cache("ttl", 1024);
+        let ttl = Duration::from_secs(60);
+        cache.put(URL, None, None, "body").unwrap();
+        assert!(cache.get(URL).unwrap().is_fresh(ttl));
+
+        age(&cache, URL, 120);
+        assert!(!cache.get(URL).unwrap().is_fresh(ttl));
+
+        cache.touch(URL).unwrap();
+        let page = cache.get(URL).unwrap();
+        assert!(page.is_fresh(ttl));
+        assert_eq!(page.body, "body");
+
+        // Для відсутньої сторінки 304 нічого не створює
+        cache.touch("https://eolymp.com/en/problems/2").unwrap();
+        assert!(cache.get("https://eolymp.com/en/problems/2").is_none());
+
+        cache.clear().unwrap();
+    }
+
+    #[test]
+    fn eviction_keeps_recently_confirmed_pages() {
+        let cache = temp_cache("evict", 10);
+        let (first, second, third) = ("https://a.test/1", "https://a.test/2", "https://a.test/3");
+        cache.put(first, None, None, "aaaa").unwrap();
+        cache.put(second, None, None, "bbbb").unwrap();
+        age(&cache, first, 200);
+        age(&cache, second, 100);
+
+        // Перша сторінка давніша за тілом, але сервер щойно підтвердив її через 304
+        cache.touch(first).unwrap();
+        cache.put(third, None, None, "cccc").unwrap();
+
+        assert!(cache.get(first).is_some());
+        assert!(cache.get(second).is_none());
+        assert!(cache.get(third).is_some());
+        assert_eq!(cache.size(), 8);
+
+        cache.clear().unwrap();
+        assert_eq!(cache.size(), 0);
+    }
+}
@@ -0,0 +1,251 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use reqwest::Url;
+use serde::Deserialize;
+
+use crate::api;
+use crate::locale::Locale;
+
+pub const DEFAULT_BASE_URL: &str = "https://eolymp.com";
+pub const DEFAULT_PROBLEM_URL: &str = "{base}/{locale}/problems/{id}";
+const CONFIG_FILE: &str = "eolymp.toml";
+const PLACEHOLDERS: [&str; 3] = ["base", "locale", "id"];
+
+/// Адреси сайту та API. Джерела за пріоритетом: аргументи командного рядка,
+/// змінні середовища `EOLYMP_*`, файл `eolymp.toml`, а потім значення за замовчуванням.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Endpoints {
+    pub base_url: String,
+    pub problem_url: String,
+    pub api_url: String,
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            problem_url: DEFAULT_PROBLEM_URL.to_string(),
+            api_url: api::DEFAULT_ENDPOINT.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Overrides {
+    base_url: Option<String>,
+    problem_url: Option<String>,
+    api_url: Option<String>,
+}
+
+impl Overrides {
+    fn from_args(args: &[String]) -> (PathBuf, Self, Vec<String>) {
+        let mut config_file = PathBuf::from(CONFIG_FILE);
+        let mut overrides = Overrides::default();
+        let mut errors = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline) = match arg.split_once('=') {
+                Some((flag, value)) => (flag, Some(value.to_string())),
+                None => (arg.as_str(), None),
+            };
+            let slot = match flag {
+                "--base-url" => &mut overrides.base_url,
+                "--problem-url" => &mut overrides.problem_url,
+                "--api-url" => &mut overrides.api_url,
+                "--config" => {
+                    match inline.or_else(|| args.next().cloned()) {
+                        Some(path) => config_file = PathBuf::from(path),
+                        None => errors.push("--config: missing value".to_string()),
+                    }
+                    continue;
+                }
+                _ => {
+                    errors.push(format!("unknown argument: {}", arg));
+                    continue;
+                }
+            };
+
+            match inline.or_else(|| args.next().cloned()) {
+                Some(value) => *slot = Some(value),
+                None => errors.push(format!("{}: missing value", flag)),
+            }
+        }
+
+        (config_file, overrides, errors)
+    }
+
+    fn from_env() -> Self {
+        Self {
+            base_url: env::var("EOLYMP_BASE_URL").ok(),
+            problem_url: env::var("EOLYMP_PROBLEM_URL").ok(),
+            api_url: env::var("EOLYMP_API_URL").ok(),
+        }
+    }
+
+    fn from_file(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).map_err(|e| format!("{}: {}", path.display(), e)),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+}
+
+impl Endpoints {
+    /// Збирає адреси з усіх джерел; некоректне значення пропускається з повідомленням,
+    /// і діє наступне за пріоритетом джерело.
+    pub fn load() -> (Self, Vec<String>) {
+        let args = env::args().skip(1).collect::<Vec<_>>();
+        let (config_file, cli, mut errors) = Overrides::from_args(&args);
+
+        let file = Overrides::from_file(&config_file).unwrap_or_else(|e| {
+            errors.push(e);
+            Overrides::default()
+        });
+        let sources = [("command line", cli), ("environment", Overrides::from_env()), ("config file", file)];
+
+        let mut endpoints = Endpoints::default();
+        if let Some(base_url) = pick(&sources, &mut errors, |o| &o.base_url, validate_base_url) {
+            endpoints.base_url = base_url;
+        }
+        if let Some(api_url) = pick(&sources, &mut errors, |o| &o.api_url, validate_base_url) {
+            endpoints.api_url = api_url;
+        }
+        let base_url = endpoints.base_url.clone();
+        if let Some(problem_url) = pick(&sources, &mut errors, |o| &o.problem_url, |t| validate_template(t, &base_url)) {
+            endpoints.problem_url = problem_url;
+        }
+
+        (endpoints, errors)
+    }
+
+    pub fn problem_url(&self, id: &str, locale: Locale) -> String {
+        render(&self.problem_url, &self.base_url, id, locale)
+    }
+}
+
+// Перше коректне значення серед джерел; некоректні потрапляють до списку помилок
+fn pick(
+    sources: &[(&str, Overrides)],
+    errors: &mut Vec<String>,
+    get: impl Fn(&Overrides) -> &Option<String>,
+    validate: impl Fn(&str) -> Result<String, String>,
+) -> Option<String> {
+    for (source, overrides) in sources {
+        if let Some(value) = get(overrides) {
+            match validate(value) {
+                Ok(value) => return Some(value),
+                Err(e) => errors.push(format!("{} ({}): {}", source, value, e)),
+            }
+        }
+    }
+    None
+}
+
+fn render(template: &str, base_url: &str, id: &str, locale: Locale) -> String {
+    template
+        .replace("{base}", base_url)
+        .replace("{locale}", locale.code())
+        .replace("{id}", id)
+}
+
+fn parse_http_url(value: &str) -> Result<Url, String> {
+    let url = Url::parse(value).map_err(|e| e.to_string())?;
+
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err("only http and https are supported".to_string());
+    }
+    Ok(url)
+}
+
+fn validate_base_url(value: &str) -> Result<String, String> {
+    let value = value.trim().trim_end_matches('/');
+    let url = parse_http_url(value)?;
+
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err("must not contain a query or fragment".to_string());
+    }
+    Ok(value.to_string())
+}
+
+/// Шаблон може містити лише `{base}`, `{locale}` та `{id}`, причому `{id}` обов'язковий.
+fn validate_template(template: &str, base_url: &str) -> Result<String, String> {
+    let template = template.trim();
+
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let end = rest[start..]
+            .find('}')
+            .ok_or_else(|| "unclosed placeholder".to_string())?;
+        let name = &rest[start + 1..start + end];
+        if !PLACEHOLDERS.contains(&name) {
+            return Err(format!("unknown placeholder {{{}}}", name));
+        }
+        rest = &rest[start + end + 1..];
+    }
+
+    if !template.contains("{id}") {
+        return Err("the template must contain {id}".to_string());
+    }
+
+    parse_http_url(&render(template, base_url, "1", Locale::default()))?;
+    Ok(template.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn default_template_renders_problem_url() {
+        assert_eq!(Endpoints::default().problem_url("42", Locale::En), "https://eolymp.com/en/problems/42");
+    }
+
+    #[test]
+    fn base_url_is_normalized() {
+        assert_eq!(validate_base_url(" http://localhost:8080/ "), Ok("http://localhost:8080".to_string()));
+        assert!(validate_base_url("ftp://mirror.example").is_err());
+        assert!(validate_base_url("eolymp.com").is_err());
+        assert!(validate_base_url("https://mirror.example/?x=1").is_err());
+    }
+
+    #[test]
+    fn template_placeholders_are_checked() {
+        let base = DEFAULT_BASE_URL;
+        assert!(validate_template("{base}/problems/{id}?lang={locale}", base).is_ok());
+        assert!(validate_template("https://mirror.example/p/{id}", base).is_ok());
+        assert!(validate_template("{base}/problems", base).is_err());
+        assert!(validate_template("{base}/problems/{number}", base).is_err());
+        assert!(validate_template("{base}/problems/{id", base).is_err());
+        assert!(validate_template("/problems/{id}", base).is_err());
+    }
+
+    #[test]
+    fn command_line_flags_are_parsed() {
+        let (config, overrides, errors) = Overrides::from_args(&args(&[
+            "--base-url=http://127.0.0.1:9000",
+            "--problem-url",
+            "{base}/p/{id}",
+            "--config",
+            "mirror.toml",
+        ]));
+
+        assert!(errors.is_empty());
+        assert_eq!(config, PathBuf::from("mirror.toml"));
+        assert_eq!(overrides.base_url.as_deref(), Some("http://127.0.0.1:9000"));
+        assert_eq!(overrides.problem_url.as_deref(), Some("{base}/p/{id}"));
+    }
+
+    #[test]
+    fn unknown_flags_are_reported() {
+        let (_, _, errors) = Overrides::from_args(&args(&["--verbose", "--api-url"]));
+        assert_eq!(errors.len(), 2);
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Duration, NaiveDateTime};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::db::{Contest, ContestProblem, HistoryEntry};
+use crate::fetcher::{FetchError, RequestId};
+use crate::judge::{self, Judge};
+
+pub const MAX_PROBLEMS: usize = 12;
+pub const DEFAULT_PROBLEMS: usize = 5;
+pub const DEFAULT_DURATION_MINUTES: u32 = 120;
+pub const MAX_DURATION_MINUTES: u32 = 600;
+
+// Штраф ICPC за кожну невдалу спробу розв'язаної задачі
+const PENALTY_PER_ATTEMPT: i32 = 20;
+
+// Випадкових id буває менше, ніж задач у контесті, — не перебирати нескінченно
+const MAX_DRAWS: usize = 1000;
+
+// Скільки разів замінювати відсутні задачі, перш ніж відмовитися від контесту
+const MAX_REPLACEMENTS: usize = 50;
+
+/// Звідки брати задачі для контесту.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Source {
+    Random,
+    Saved,
+}
+
+/// Налаштування контесту, який ще не почався.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Draft {
+    pub source: Source,
+    pub count: usize,
+    pub duration_minutes: u32,
+    pub judges: Vec<Judge>,
+    /// Діапазон складності; задачі з відомою складністю беруться з історії показаних.
+    pub difficulty: Option<(u32, u32)>,
+    pub selected: Vec<i32>,
+}
+
+impl Draft {
+    pub fn new(judges: Vec<Judge>) -> Self {
+        Self {
+            source: Source::Random,
+            count: DEFAULT_PROBLEMS,
+            duration_minutes: DEFAULT_DURATION_MINUTES,
+            judges,
+            difficulty: None,
+            selected: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub judge: Judge,
+    pub problem_id: String,
+    pub name: String,
+}
+
+/// Випадкові задачі за фільтрами, без повторів. Із фільтром складності кандидатами є лише
+/// задачі з історії, тож їх може виявитися менше, ніж `count`.
+pub fn pick_random(rng: &mut impl Rng, draft: &Draft, history: &[HistoryEntry]) -> Vec<Candidate> {
+    if draft.judges.is_empty() {
+        return Vec::new();
+    }
+
+    if let Some((min, max)) = draft.difficulty {
+        let mut candidates = history
+            .iter()
+            .filter(|entry| entry.difficulty.is_some_and(|d| (min as i32..=max as i32).contains(&d)))
+            .filter_map(|entry| {
+                let judge = Judge::from_code(&entry.judge).filter(|judge| draft.judges.contains(judge))?;
+                Some(Candidate { judge, problem_id: entry.problem_id.clone(), name: entry.name.clone() })
+            })
+            .collect::<Vec<_>>();
+        candidates.shuffle(rng);
+        candidates.truncate(draft.count);
+        return candidates;
+    }
+
+    let mut seen = HashSet::new();
+    let mut picked = Vec::new();
+    for _ in 0..MAX_DRAWS {
+        if picked.len() == draft.count {
+            break;
+        }
+        let (judge, problem_id) = judge::random_problem(rng, &draft.judges);
+        if seen.insert((judge, problem_id.clone())) {
+            picked.push(Candidate { judge, problem_id, name: String::new() });
+        }
+    }
+    picked
+}
+
+/// Випадковий контест, задачі якого ще перевіряються. Назви завантажуються тим самим шляхом,
+/// що й для Generate; відсутню задачу замінює новий кандидат, а таймер стартує лише тоді,
+/// коли всі назви відомі.
+#[derive(Debug, Clone)]
+pub struct Preparation {
+    pub duration_minutes: u32,
+    judges: Vec<Judge>,
+    problems: Vec<Candidate>,
+    pending: HashMap<RequestId, usize>,
+    replacements: usize,
+}
+
+impl Preparation {
+    pub fn new(draft: &Draft, problems: Vec<Candidate>) -> Self {
+        Self {
+            duration_minutes: draft.duration_minutes,
+            judges: draft.judges.clone(),
+            problems,
+            pending: HashMap::new(),
+            replacements: 0,
+        }
+    }
+
+    /// Позиції задач, назви яких ще невідомі; задачі з історії та збережені вже перевірено.
+    pub fn unchecked(&self) -> Vec<usize> {
+        (0..self.problems.len()).filter(|&position| self.problems[position].name.is_empty()).collect()
+    }
+
+    pub fn candidate(&self, position: usize) -> Option<&Candidate> {
+        self.problems.get(position)
+    }
+
+    pub fn track(&mut self, request_id: RequestId, position: usize) {
+        self.pending.insert(request_id, position);
+    }
+
+    pub fn owns(&self, request_id: RequestId) -> bool {
+        self.pending.contains_key(&request_id)
+    }
+
+    /// Скільки задач уже перевірено із загальної кількості.
+    pub fn progress(&self) -> (usize, usize) {
+        (self.problems.len() - self.unchecked().len(), self.problems.len())
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.pending.is_empty() && self.unchecked().is_empty()
+    }
+
+    pub fn into_problems(self) -> Vec<Candidate> {
+        self.problems
+    }
+
+    /// Обробляє відповідь на перевірку. `Ok(Some(position))` — задачу на цій позиції замінено
+    /// і її теж треба перевірити; помилка означає, що контест зібрати не вдалося.
+    pub fn receive(
+        &mut self,
+        rng: &mut impl Rng,
+        request_id: RequestId,
+        result: Result<String, FetchError>,
+    ) -> Result<Option<usize>, FetchError> {
+        let Some(position) = self.pending.remove(&request_id) else {
+            return Ok(None);
+        };
+
+        match result {
+            Ok(title) => {
+                self.problems[position].name = title;
+                Ok(None)
+            }
+            Err(e) if e.is_missing() => {
+                while self.replacements < MAX_REPLACEMENTS {
+                    self.replacements += 1;
+                    let (judge, problem_id) = judge::random_problem(rng, &self.judges);
+                    if !self.problems.iter().any(|problem| problem.judge == judge && problem.problem_id == problem_id) {
+                        self.problems[position] = Candidate { judge, problem_id, name: String::new() };
+                        return Ok(Some(position));
+                    }
+                }
+                Err(e)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Буква задачі в контесті: A, B, C…
+pub fn letter(position: i32) -> char {
+    (b'A' + position.clamp(0, 25) as u8) as char
+}
+
+/// Підсумок за правилами ICPC: кількість розв'язаних задач і сумарний штраф у хвилинах.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Standing {
+    pub solved: u32,
+    pub penalty: i32,
+}
+
+pub fn penalty(problem: &ContestProblem) -> Option<i32> {
+    problem.solved_minute.map(|minute| minute + PENALTY_PER_ATTEMPT * problem.attempts)
+}
+
+pub fn standing(problems: &[ContestProblem]) -> Standing {
+    problems
+        .iter()
+        .filter_map(penalty)
+        .fold(Standing::default(), |standing, penalty| Standing {
+            solved: standing.solved + 1,
+            penalty: standing.penalty + penalty,
+        })
+}
+
+pub fn ends_at(contest: &Contest) -> NaiveDateTime {
+    contest.started_at + Duration::minutes(contest.duration_minutes as i64)
+}
+
+pub fn remaining(contest: &Contest, now: NaiveDateTime) -> Duration {
+    (ends_at(contest) - now).max(Duration::zero())
+}
+
+/// Хвилина контесту, яка зараховується як час розв'язку.
+pub fn elapsed_minutes(contest: &Contest, now: NaiveDateTime) -> i32 {
+    (now - contest.started_at).num_minutes().clamp(0, contest.duration_minutes as i64) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn problem(position: i32, attempts: i32, solved_minute: Option<i32>) -> ContestProblem {
+        ContestProblem {
+            contest: 1,
+            position,
+            judge: "codeforces".to_string(),
+            problem_id: format!("{}A", position + 1),
+            name: String::new(),
+            url: String::new(),
+            attempts,
+            solved_minute,
+        }
+    }
+
+    fn history(id: &str, difficulty: Option<i32>) -> HistoryEntry {
+        HistoryEntry {
+            judge: "codeforces".to_string(),
+            problem_id: id.to_string(),
+            name: id.to_string(),
+            tags: String::new(),
+            difficulty,
+            shown: 1,
+            opened: 0,
+            last_shown_at: NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+        }
+    }
+
+    fn unnamed(id: &str) -> Candidate {
+        Candidate { judge: Judge::Timus, problem_id: id.to_string(), name: String::new() }
+    }
+
+    #[test]
+    fn missing_problems_are_replaced_before_start() {
+        let mut rng = rand::rng();
+        let draft = Draft::new(vec![Judge::Timus]);
+        let mut preparation = Preparation::new(&draft, vec![unnamed("1000"), unnamed("9999")]);
+        assert_eq!(preparation.unchecked(), vec![0, 1]);
+        preparation.track(1, 0);
+        preparation.track(2, 1);
+
+        assert_eq!(preparation.receive(&mut rng, 1, Ok("A+B Problem".to_string())), Ok(None));
+        assert_eq!(preparation.receive(&mut rng, 2, Err(FetchError::Status(404))), Ok(Some(1)));
+        assert!(!preparation.is_ready());
+        assert_eq!(preparation.progress(), (1, 2));
+
+        let replacement = preparation.candidate(1).cloned().unwrap();
+        assert_ne!(replacement.problem_id, "9999");
+        assert!(replacement.name.is_empty());
+
+        preparation.track(3, 1);
+        assert!(!preparation.owns(2));
+        assert_eq!(preparation.receive(&mut rng, 3, Ok("Sum".to_string())), Ok(None));
+        assert!(preparation.is_ready());
+
+        let names = preparation.into_problems().into_iter().map(|problem| problem.name).collect::<Vec<_>>();
+        assert_eq!(names, vec!["A+B Problem", "Sum"]);
+    }
+
+    #[test]
+    fn transient_failure_abandons_preparation() {
+        let mut rng = rand::rng();
+        let draft = Draft::new(vec![Judge::Timus]);
+        let mut preparation = Preparation::new(&draft, vec![unnamed("1000")]);
+        preparation.track(1, 0);
+
+        assert_eq!(preparation.receive(&mut rng, 1, Err(FetchError::Timeout)), Err(FetchError::Timeout));
+        // Невідома відповідь нічого не змінює
+        assert_eq!(preparation.receive(&mut rng, 7, Ok("A+B Problem".to_string())), Ok(None));
+        assert!(!preparation.is_ready());
+
+        let named = Candidate { name: "A+B Problem".to_string(), ..unnamed("1000") };
+        assert!(Preparation::new(&draft, vec![named]).is_ready());
+    }
+
+    #[test]
+    fn icpc_penalty_counts_only_solved_problems() {
+        let problems = vec![
+            problem(0, 0, Some(15)),
+            problem(1, 2, Some(70)),
+            problem(2, 5, None),
+        ];
+
+        assert_eq!(penalty(&problems[1]), Some(110));
+        assert_eq!(penalty(&problems[2]), None);
+        assert_eq!(standing(&problems), Standing { solved: 2, penalty: 125 });
+    }
+
+    #[test]
+    fn timer_is_clamped_to_contest_duration() {
+        let started_at = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+        let contest = Contest { id: 1, started_at, duration_minutes: 60, finished_at: None };
+
+        let now = started_at + Duration::seconds(25 * 60 + 30);
+        assert_eq!(elapsed_minutes(&contest, now), 25);
+        assert_eq!(remaining(&contest, now), Duration::seconds(34 * 60 + 30));
+
+        let late = started_at + Duration::hours(3);
+        assert_eq!(elapsed_minutes(&contest, late), 60);
+        assert_eq!(remaining(&contest, late), Duration::zero());
+    }
+
+    #[test]
+    fn random_picks_respect_filters() {
+        let mut rng = rand::rng();
+        let mut draft = Draft::new(vec![Judge::Timus]);
+
+        let picked = pick_random(&mut rng, &draft, &[]);
+        assert_eq!(picked.len(), DEFAULT_PROBLEMS);
+        assert!(picked.iter().all(|candidate| candidate.judge == Judge::Timus));
+
+        draft.judges = vec![Judge::Codeforces];
+        draft.difficulty = Some((1000, 1400));
+        let known = vec![history("1A", Some(800)), history("2B", Some(1200)), history("3C", None)];
+        let picked = pick_random(&mut rng, &draft, &known);
+        assert_eq!(picked, vec![Candidate { judge: Judge::Codeforces, problem_id: "2B".to_string(), name: "2B".to_string() }]);
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::{Datelike, Days, Local, NaiveDate};
+use rand::rngs::ChaCha8Rng;
+use rand::SeedableRng;
+
+use crate::cache;
+use crate::fetcher::FetchError;
+use crate::judge::{self, Judge};
+
+/// Скільки кандидатів перебирати, якщо випадкової задачі дня не існує.
+pub const MAX_ATTEMPTS: u32 = 10;
+
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+// ChaCha8 відтворюється однаково на всіх платформах, тож у всієї команди та сама задача
+fn seed(date: NaiveDate, salt: &str) -> u64 {
+    cache::fnv1a(&format!("{}#{}", date.format("%Y-%m-%d"), salt.trim()))
+}
+
+/// Задача дня: `attempt`-й кандидат із послідовності, заданої датою та сіллю команди.
+pub fn pick(date: NaiveDate, salt: &str, judges: &[Judge], attempt: u32) -> (Judge, String) {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed(date, salt));
+
+    let mut candidate = judge::random_problem(&mut rng, judges);
+    for _ in 0..attempt {
+        candidate = judge::random_problem(&mut rng, judges);
+    }
+    candidate
+}
+
+/// Номер наступного кандидата, якщо задачі `attempt` не існує.
+/// Тимчасові збої та вичерпані спроби кандидата не змінюють.
+pub fn next_attempt<T>(result: &Result<T, FetchError>, attempt: u32) -> Option<u32> {
+    let missing = matches!(result, Err(e) if e.is_missing());
+    (missing && attempt + 1 < MAX_ATTEMPTS).then_some(attempt + 1)
+}
+
+/// Дні місяця для календаря, вирівняні за тижнями з понеділка; `None` — порожня клітинка.
+pub fn month_grid(month: NaiveDate) -> Vec<Option<NaiveDate>> {
+    let first = month.with_day(1).unwrap_or(month);
+    let offset = first.weekday().num_days_from_monday() as usize;
+
+    let mut cells = vec![None; offset];
+    cells.extend(
+        first.iter_days()
+            .take_while(|day| day.month() == first.month())
+            .map(Some)
+    );
+    cells
+}
+
+pub fn shift_month(month: NaiveDate, forward: bool) -> NaiveDate {
+    let first = month.with_day(1).unwrap_or(month);
+    if forward {
+        first.checked_add_days(Days::new(31)).and_then(|day| day.with_day(1)).unwrap_or(first)
+    }
+    else {
+        first.pred_opt().and_then(|day| day.with_day(1)).unwrap_or(first)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn pick_is_stable_for_date_and_salt() {
+        let judges = Judge::ALL;
+        let day = date(2025, 3, 14);
+
+        assert_eq!(pick(day, "team", &judges, 0), pick(day, " team ", &judges, 0));
+        assert_ne!(pick(day, "team", &judges, 0), pick(day, "other", &judges, 0));
+        assert_ne!(pick(day, "team", &judges, 0), pick(day, "team", &judges, 1));
+
+        let (judge, id) = pick(day, "team", &[Judge::Timus], 3);
+        assert_eq!(judge, Judge::Timus);
+        assert_eq!(judge.parse_id(&id), Some(id));
+    }
+
+    #[test]
+    fn only_missing_problems_advance_the_candidate() {
+        let missing: [Result<String, FetchError>; 4] = [
+            Err(FetchError::NotFound),
+            Err(FetchError::ErrorPage("404".to_string())),
+            Err(FetchError::Status(404)),
+            Err(FetchError::NoTitle),
+        ];
+        for result in &missing {
+            assert_eq!(next_attempt(result, 0), Some(1));
+        }
+        assert_eq!(next_attempt(&missing[2], MAX_ATTEMPTS - 1), None);
+
+        assert_eq!(next_attempt(&Ok("A + B".to_string()), 0), None);
+        assert_eq!(next_attempt::<String>(&Err(FetchError::Status(503)), 0), None);
+        assert_eq!(next_attempt::<String>(&Err(FetchError::Timeout), 0), None);
+        assert_eq!(next_attempt::<String>(&Err(FetchError::LoginRequired), 0), None);
+    }
+
+    #[test]
+    fn month_grid_starts_on_monday() {
+        // 1 лютого 2025 — субота
+        let grid = month_grid(date(2025, 2, 20));
+        assert_eq!(grid.iter().take_while(|cell| cell.is_none()).count(), 5);
+        assert_eq!(grid.iter().flatten().count(), 28);
+        assert_eq!(grid.last(), Some(&Some(date(2025, 2, 28))));
+    }
+
+    #[test]
+    fn months_shift_across_years() {
+        assert_eq!(shift_month(date(2024, 12, 31), true), date(2025, 1, 1));
+        assert_eq!(shift_month(date(2025, 1, 15), false), date(2024, 12, 1));
+    }
+}
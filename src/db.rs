@@ -1,14 +1,284 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use diesel::connection::SimpleConnection;
 use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Date, Double, Integer, Nullable, Text};
 use diesel::sqlite::SqliteConnection;
 
 const DATABASE_URL: &str = "eolymp.db";
 
+// Кожна міграція виконується рівно один раз, номер останньої зберігається у `PRAGMA user_version`
+const MIGRATIONS: &[&str] = &[
+    "ALTER TABLE problems ADD COLUMN locale TEXT",
+    "CREATE TABLE IF NOT EXISTS settings (
+        key TEXT PRIMARY KEY NOT NULL,
+        value TEXT NOT NULL
+    )",
+    "ALTER TABLE problems ADD COLUMN deleted_at TIMESTAMP",
+    "CREATE TABLE IF NOT EXISTS error_log (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        severity TEXT NOT NULL,
+        message TEXT NOT NULL,
+        details TEXT,
+        created_at TIMESTAMP NOT NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS pending_fetches (
+        problem_id INTEGER PRIMARY KEY NOT NULL,
+        url TEXT NOT NULL,
+        locale TEXT NOT NULL,
+        created_at TIMESTAMP NOT NULL
+    )",
+    // SQLite не вміє змінювати тип стовпця, тому таблиці перебудовуються:
+    // id задач стають рядками з прив'язкою до судді, а черга посилається на рядок задачі
+    "CREATE TABLE problems_new (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        judge TEXT NOT NULL DEFAULT 'eolymp',
+        problem_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        url TEXT NOT NULL,
+        locale TEXT,
+        deleted_at TIMESTAMP,
+        UNIQUE (judge, problem_id)
+    );
+    INSERT INTO problems_new (id, judge, problem_id, name, url, locale, deleted_at)
+        SELECT id, 'eolymp', CAST(problem_id AS TEXT), name, url, locale, deleted_at FROM problems;
+    DROP TABLE problems;
+    ALTER TABLE problems_new RENAME TO problems;
+
+    CREATE TABLE pending_fetches_new (
+        problem INTEGER PRIMARY KEY NOT NULL,
+        judge TEXT NOT NULL,
+        problem_id TEXT NOT NULL,
+        url TEXT NOT NULL,
+        locale TEXT NOT NULL,
+        created_at TIMESTAMP NOT NULL
+    );
+    INSERT INTO pending_fetches_new (problem, judge, problem_id, url, locale, created_at)
+        SELECT p.id, p.judge, p.problem_id, f.url, f.locale, f.created_at
+        FROM pending_fetches f JOIN problems p ON p.problem_id = CAST(f.problem_id AS TEXT);
+    DROP TABLE pending_fetches;
+    ALTER TABLE pending_fetches_new RENAME TO pending_fetches;",
+    "CREATE TABLE IF NOT EXISTS daily_problems (
+        date DATE NOT NULL,
+        salt TEXT NOT NULL,
+        judge TEXT NOT NULL,
+        problem_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        attempt INTEGER NOT NULL,
+        PRIMARY KEY (date, salt)
+    )",
+    "CREATE TABLE IF NOT EXISTS problem_history (
+        judge TEXT NOT NULL,
+        problem_id TEXT NOT NULL,
+        name TEXT NOT NULL DEFAULT '',
+        tags TEXT NOT NULL DEFAULT '',
+        difficulty INTEGER,
+        shown INTEGER NOT NULL DEFAULT 0,
+        opened INTEGER NOT NULL DEFAULT 0,
+        last_shown_at TIMESTAMP NOT NULL,
+        PRIMARY KEY (judge, problem_id)
+    )",
+    "CREATE TABLE IF NOT EXISTS rating_history (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        judge TEXT NOT NULL,
+        problem_id TEXT NOT NULL,
+        solved BOOLEAN NOT NULL,
+        minutes INTEGER,
+        rating_before REAL NOT NULL,
+        rating_after REAL NOT NULL,
+        difficulty_before REAL NOT NULL,
+        difficulty_after REAL NOT NULL,
+        created_at TIMESTAMP NOT NULL
+    )",
+    // Уже збережені задачі стають на повторення одразу; задачі з кошика — лише після відновлення
+    "CREATE TABLE IF NOT EXISTS reviews (
+        problem INTEGER PRIMARY KEY NOT NULL,
+        ease REAL NOT NULL,
+        interval_days INTEGER NOT NULL,
+        repetitions INTEGER NOT NULL,
+        due DATE NOT NULL,
+        last_grade TEXT,
+        reviewed_at TIMESTAMP
+    );
+    CREATE TABLE IF NOT EXISTS review_log (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        problem INTEGER NOT NULL,
+        grade TEXT NOT NULL,
+        interval_days INTEGER NOT NULL,
+        reviewed_at TIMESTAMP NOT NULL
+    );
+    INSERT INTO reviews (problem, ease, interval_days, repetitions, due)
+        SELECT id, 2.5, 0, 0, date('now', 'localtime') FROM problems WHERE deleted_at IS NULL;",
+    "CREATE TABLE IF NOT EXISTS contests (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        started_at TIMESTAMP NOT NULL,
+        duration_minutes INTEGER NOT NULL,
+        finished_at TIMESTAMP
+    );
+    CREATE TABLE IF NOT EXISTS contest_problems (
+        contest INTEGER NOT NULL,
+        position INTEGER NOT NULL,
+        judge TEXT NOT NULL,
+        problem_id TEXT NOT NULL,
+        name TEXT NOT NULL,
+        url TEXT NOT NULL,
+        attempts INTEGER NOT NULL DEFAULT 0,
+        solved_minute INTEGER,
+        PRIMARY KEY (contest, position)
+    );",
+    "CREATE TABLE IF NOT EXISTS work_sessions (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        judge TEXT NOT NULL,
+        problem_id TEXT NOT NULL,
+        started_at TIMESTAMP NOT NULL,
+        ended_at TIMESTAMP
+    )",
+    // Раніше розклад отримували й задачі з кошика; ще не повторені прибираються,
+    // а після відновлення задача стає на повторення заново
+    "DELETE FROM reviews
+        WHERE reviewed_at IS NULL AND problem IN (SELECT id FROM problems WHERE deleted_at IS NOT NULL)",
+];
+
+// Розв'язані задачі: звіти для рейтингу та здані задачі контестів
+const SOLVES: &str = "
+    SELECT judge, problem_id, created_at AS solved_at FROM rating_history WHERE solved
+    UNION ALL
+    SELECT p.judge, p.problem_id, datetime(c.started_at, '+' || p.solved_minute || ' minutes') AS solved_at
+    FROM contest_problems p JOIN contests c ON c.id = p.contest
+    WHERE p.solved_minute IS NOT NULL";
+
+// Журнал помилок обрізається до цієї кількості останніх записів
+const ERROR_LOG_LIMIT: i64 = 500;
+
 table! {
     problems (id) {
         id -> Integer,
-        problem_id -> Integer,
+        judge -> Text,
+        problem_id -> Text,
+        name -> Text,
+        url -> Text,
+        locale -> Nullable<Text>,
+        deleted_at -> Nullable<Timestamp>,
+    }
+}
+
+table! {
+    error_log (id) {
+        id -> Integer,
+        severity -> Text,
+        message -> Text,
+        details -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+table! {
+    pending_fetches (problem) {
+        problem -> Integer,
+        judge -> Text,
+        problem_id -> Text,
+        url -> Text,
+        locale -> Text,
+        created_at -> Timestamp,
+    }
+}
+
+table! {
+    daily_problems (date, salt) {
+        date -> Date,
+        salt -> Text,
+        judge -> Text,
+        problem_id -> Text,
+        name -> Text,
+        attempt -> Integer,
+    }
+}
+
+table! {
+    problem_history (judge, problem_id) {
+        judge -> Text,
+        problem_id -> Text,
+        name -> Text,
+        tags -> Text,
+        difficulty -> Nullable<Integer>,
+        shown -> Integer,
+        opened -> Integer,
+        last_shown_at -> Timestamp,
+    }
+}
+
+table! {
+    rating_history (id) {
+        id -> Integer,
+        judge -> Text,
+        problem_id -> Text,
+        solved -> Bool,
+        minutes -> Nullable<Integer>,
+        rating_before -> Double,
+        rating_after -> Double,
+        difficulty_before -> Double,
+        difficulty_after -> Double,
+        created_at -> Timestamp,
+    }
+}
+
+table! {
+    reviews (problem) {
+        problem -> Integer,
+        ease -> Double,
+        interval_days -> Integer,
+        repetitions -> Integer,
+        due -> Date,
+        last_grade -> Nullable<Text>,
+        reviewed_at -> Nullable<Timestamp>,
+    }
+}
+
+table! {
+    review_log (id) {
+        id -> Integer,
+        problem -> Integer,
+        grade -> Text,
+        interval_days -> Integer,
+        reviewed_at -> Timestamp,
+    }
+}
+
+table! {
+    contests (id) {
+        id -> Integer,
+        started_at -> Timestamp,
+        duration_minutes -> Integer,
+        finished_at -> Nullable<Timestamp>,
+    }
+}
+
+table! {
+    contest_problems (contest, position) {
+        contest -> Integer,
+        position -> Integer,
+        judge -> Text,
+        problem_id -> Text,
         name -> Text,
         url -> Text,
+        attempts -> Integer,
+        solved_minute -> Nullable<Integer>,
+    }
+}
+
+table! {
+    work_sessions (id) {
+        id -> Integer,
+        judge -> Text,
+        problem_id -> Text,
+        started_at -> Timestamp,
+        ended_at -> Nullable<Timestamp>,
+    }
+}
+
+table! {
+    settings (key) {
+        key -> Text,
+        value -> Text,
     }
 }
 
@@ -16,17 +286,195 @@ table! {
 #[diesel(table_name = problems)]
 pub struct Problem {
     pub id: i32,
-    pub problem_id: i32,
+    pub judge: String,
+    pub problem_id: String,
     pub name: String,
     pub url: String,
+    pub locale: Option<String>,
+    pub deleted_at: Option<NaiveDateTime>,
 }
 
 #[derive(Insertable, AsChangeset, Debug)]
 #[diesel(table_name = problems)]
 pub struct NewProblem {
-    pub problem_id: i32,
+    pub judge: String,
+    pub problem_id: String,
     pub name: String,
     pub url: String,
+    pub locale: Option<String>,
+}
+
+#[derive(Queryable, Clone, Debug)]
+#[diesel(table_name = error_log)]
+pub struct LogEntry {
+    pub severity: String,
+    pub message: String,
+    pub details: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+/// Збережена без назви задача, назву якої треба завантажити, щойно з'явиться мережа.
+#[derive(Queryable, Clone, Debug)]
+#[diesel(table_name = pending_fetches)]
+pub struct PendingFetch {
+    pub problem: i32,
+    pub judge: String,
+    pub problem_id: String,
+    pub url: String,
+    pub locale: String,
+}
+
+/// Задача дня, яку вже було відкрито; `attempt` — номер кандидата, що виявився існуючою задачею.
+#[derive(Queryable, Insertable, Clone, Debug)]
+#[diesel(table_name = daily_problems)]
+pub struct DailyProblem {
+    pub date: NaiveDate,
+    pub salt: String,
+    pub judge: String,
+    pub problem_id: String,
+    pub name: String,
+    pub attempt: i32,
+}
+
+/// Задача, яку вже показували: скільки разів її показано та відкрито, теми й складність.
+#[derive(Queryable, Clone, Debug)]
+#[diesel(table_name = problem_history)]
+pub struct HistoryEntry {
+    pub judge: String,
+    pub problem_id: String,
+    pub name: String,
+    pub tags: String,
+    pub difficulty: Option<i32>,
+    pub shown: i32,
+    pub opened: i32,
+    pub last_shown_at: NaiveDateTime,
+}
+
+impl HistoryEntry {
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.tags.split(',').map(str::trim).filter(|tag| !tag.is_empty())
+    }
+}
+
+/// Звіт про спробу розв'язати задачу та зміна рейтингу після неї.
+#[derive(Queryable, Insertable, Clone, Debug)]
+#[diesel(table_name = rating_history)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct RatingChange {
+    #[diesel(skip_insertion)]
+    pub id: i32,
+    pub judge: String,
+    pub problem_id: String,
+    pub solved: bool,
+    pub minutes: Option<i32>,
+    pub rating_before: f64,
+    pub rating_after: f64,
+    pub difficulty_before: f64,
+    pub difficulty_after: f64,
+    pub created_at: NaiveDateTime,
+}
+
+/// Розклад повторення збереженої задачі за SM-2.
+#[derive(Queryable, Insertable, AsChangeset, Clone, Debug, PartialEq)]
+#[diesel(table_name = reviews)]
+#[diesel(treat_none_as_null = true)]
+pub struct Review {
+    pub problem: i32,
+    pub ease: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub due: NaiveDate,
+    pub last_grade: Option<String>,
+    pub reviewed_at: Option<NaiveDateTime>,
+}
+
+/// Віртуальний контест; поки `finished_at` порожнє, він триває.
+#[derive(Queryable, Clone, Debug, PartialEq)]
+#[diesel(table_name = contests)]
+pub struct Contest {
+    pub id: i32,
+    pub started_at: NaiveDateTime,
+    pub duration_minutes: i32,
+    pub finished_at: Option<NaiveDateTime>,
+}
+
+/// Задача контесту: `attempts` — невдалі спроби, `solved_minute` — хвилина контесту, коли її здано.
+#[derive(Queryable, Insertable, Clone, Debug, PartialEq)]
+#[diesel(table_name = contest_problems)]
+pub struct ContestProblem {
+    pub contest: i32,
+    pub position: i32,
+    pub judge: String,
+    pub problem_id: String,
+    pub name: String,
+    pub url: String,
+    pub attempts: i32,
+    pub solved_minute: Option<i32>,
+}
+
+/// Проміжок роботи над задачею, який ще триває.
+#[derive(Queryable, Clone, Debug)]
+#[diesel(table_name = work_sessions)]
+pub struct OpenSession {
+    pub id: i32,
+    pub judge: String,
+    pub problem_id: String,
+    pub started_at: NaiveDateTime,
+}
+
+#[derive(QueryableByName, Clone, Debug)]
+pub struct ProblemTime {
+    #[diesel(sql_type = Text)]
+    pub judge: String,
+    #[diesel(sql_type = Text)]
+    pub problem_id: String,
+    #[diesel(sql_type = BigInt)]
+    pub seconds: i64,
+}
+
+#[derive(QueryableByName, Clone, Debug)]
+pub struct DayCount {
+    #[diesel(sql_type = Date)]
+    pub day: NaiveDate,
+    #[diesel(sql_type = BigInt)]
+    pub count: i64,
+}
+
+#[derive(QueryableByName, Clone, Debug)]
+pub struct BucketCount {
+    #[diesel(sql_type = Integer)]
+    pub bucket: i32,
+    #[diesel(sql_type = BigInt)]
+    pub count: i64,
+}
+
+#[derive(QueryableByName, Clone, Debug)]
+pub struct TagCount {
+    #[diesel(sql_type = Text)]
+    pub tag: String,
+    #[diesel(sql_type = BigInt)]
+    pub count: i64,
+}
+
+/// Загальні підсумки: скільки задач розв'язано, скільки збережених серед них і середній час.
+#[derive(QueryableByName, Clone, Debug, Default)]
+pub struct SolveSummary {
+    #[diesel(sql_type = BigInt)]
+    pub solved: i64,
+    #[diesel(sql_type = BigInt)]
+    pub saved: i64,
+    #[diesel(sql_type = BigInt)]
+    pub saved_solved: i64,
+    #[diesel(sql_type = Nullable<Double>)]
+    pub average_minutes: Option<f64>,
+    #[diesel(sql_type = BigInt)]
+    pub timed: i64,
+}
+
+#[derive(QueryableByName)]
+struct UserVersion {
+    #[diesel(sql_type = Integer)]
+    user_version: i32,
 }
 
 pub struct Database {
@@ -35,7 +483,11 @@ pub struct Database {
 
 impl Database {
     pub fn new() -> Result<Self, ConnectionError> {
-        let mut connection = SqliteConnection::establish(DATABASE_URL)?;
+        Self::open(DATABASE_URL)
+    }
+
+    fn open(url: &str) -> Result<Self, ConnectionError> {
+        let mut connection = SqliteConnection::establish(url)?;
 
         diesel::sql_query(
             "CREATE TABLE IF NOT EXISTS problems (
@@ -48,35 +500,770 @@ impl Database {
             .execute(&mut connection)
             .expect("Помилка при створенні таблиці");
 
+        Self::migrate(&mut connection).expect("Помилка при міграції бази даних");
+
         Ok(Database { connection })
     }
 
-    pub fn save_problem(&mut self, problem_id: i32, name: String, url: String) -> Result<(), diesel::result::Error> {
+    fn migrate(connection: &mut SqliteConnection) -> Result<(), diesel::result::Error> {
+        let version = diesel::sql_query("PRAGMA user_version")
+            .get_result::<UserVersion>(connection)?
+            .user_version as usize;
+
+        for (idx, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+            connection.transaction(|conn| {
+                conn.batch_execute(migration)?;
+                diesel::sql_query(format!("PRAGMA user_version = {}", idx + 1)).execute(conn)?;
+                Ok::<_, diesel::result::Error>(())
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Повертає id рядка збереженої задачі.
+    pub fn save_problem(&mut self, judge: &str, problem_id: &str, name: String, url: String, locale: &str) -> Result<i32, diesel::result::Error> {
         let new_problem = NewProblem {
-            problem_id,
+            judge: judge.to_string(),
+            problem_id: problem_id.to_string(),
             name,
-            url: url.to_string(),
+            url,
+            locale: Some(locale.to_string()),
         };
 
+        // Одна й та сама задача в різних локалях — це один запис,
+        // а повторне збереження повертає її з кошика
         diesel::insert_into(problems::table)
             .values(&new_problem)
-            .on_conflict(problems::problem_id)
+            .on_conflict((problems::judge, problems::problem_id))
             .do_update()
-            .set((&new_problem))
+            .set((&new_problem, problems::deleted_at.eq(None::<NaiveDateTime>)))
             .execute(&mut self.connection)?;
 
-        Ok(())
+        problems::table
+            .filter(problems::judge.eq(judge))
+            .filter(problems::problem_id.eq(problem_id))
+            .select(problems::id)
+            .first(&mut self.connection)
     }
 
     pub fn get_all_problems(&mut self) -> Result<Vec<Problem>, diesel::result::Error> {
         problems::table
+            .filter(problems::deleted_at.is_null())
             .load::<Problem>(&mut self.connection)
     }
 
-    pub fn delete_problem(&mut self, problem_id: i32) -> Result<(), diesel::result::Error> {
-        diesel::delete(problems::table.filter(problems::problem_id.eq(problem_id)))
+    pub fn get_deleted_problems(&mut self) -> Result<Vec<Problem>, diesel::result::Error> {
+        problems::table
+            .filter(problems::deleted_at.is_not_null())
+            .order(problems::deleted_at.desc())
+            .load::<Problem>(&mut self.connection)
+    }
+
+    /// Назва належить старій мові, тож вона очищається, доки не завантажиться нова.
+    pub fn set_problem_locale(&mut self, id: i32, locale: &str, url: String) -> Result<(), diesel::result::Error> {
+        diesel::update(problems::table.find(id))
+            .set((problems::locale.eq(locale), problems::url.eq(url), problems::name.eq("")))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    // Задача лише переноситься до кошика, остаточно її видаляє `purge_problem`
+    pub fn delete_problem(&mut self, id: i32) -> Result<(), diesel::result::Error> {
+        diesel::update(problems::table.find(id))
+            .set(problems::deleted_at.eq(chrono::Utc::now().naive_utc()))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    pub fn restore_problem(&mut self, id: i32) -> Result<(), diesel::result::Error> {
+        diesel::update(problems::table.find(id))
+            .set(problems::deleted_at.eq(None::<NaiveDateTime>))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    // Залежні рядки видаляються в одній транзакції із задачею, щоб не лишилося сиріт
+    pub fn purge_problem(&mut self, id: i32) -> Result<(), diesel::result::Error> {
+        self.connection.transaction(|conn| {
+            let purged = diesel::delete(
+                problems::table
+                    .filter(problems::id.eq(id))
+                    .filter(problems::deleted_at.is_not_null())
+            )
+                .execute(conn)?;
+
+            if purged > 0 {
+                diesel::delete(pending_fetches::table.find(id))
+                    .execute(conn)?;
+                diesel::delete(reviews::table.find(id))
+                    .execute(conn)?;
+                diesel::delete(review_log::table.filter(review_log::problem.eq(id)))
+                    .execute(conn)?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn purge_deleted_problems(&mut self) -> Result<(), diesel::result::Error> {
+        self.connection.transaction(|conn| {
+            let ids = problems::table
+                .filter(problems::deleted_at.is_not_null())
+                .select(problems::id)
+                .load::<i32>(conn)?;
+
+            diesel::delete(pending_fetches::table.filter(pending_fetches::problem.eq_any(&ids)))
+                .execute(conn)?;
+            diesel::delete(reviews::table.filter(reviews::problem.eq_any(&ids)))
+                .execute(conn)?;
+            diesel::delete(review_log::table.filter(review_log::problem.eq_any(&ids)))
+                .execute(conn)?;
+            diesel::delete(problems::table.filter(problems::id.eq_any(&ids)))
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    pub fn set_problem_name(&mut self, id: i32, name: &str) -> Result<(), diesel::result::Error> {
+        diesel::update(problems::table.find(id))
+            .set(problems::name.eq(name))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    pub fn enqueue_fetch(&mut self, problem: &Problem, url: &str, locale: &str) -> Result<(), diesel::result::Error> {
+        diesel::insert_into(pending_fetches::table)
+            .values((
+                pending_fetches::problem.eq(problem.id),
+                pending_fetches::judge.eq(&problem.judge),
+                pending_fetches::problem_id.eq(&problem.problem_id),
+                pending_fetches::url.eq(url),
+                pending_fetches::locale.eq(locale),
+                pending_fetches::created_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .on_conflict(pending_fetches::problem)
+            .do_update()
+            .set((pending_fetches::url.eq(url), pending_fetches::locale.eq(locale)))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    pub fn get_pending_fetches(&mut self) -> Result<Vec<PendingFetch>, diesel::result::Error> {
+        pending_fetches::table
+            .select((
+                pending_fetches::problem,
+                pending_fetches::judge,
+                pending_fetches::problem_id,
+                pending_fetches::url,
+                pending_fetches::locale,
+            ))
+            .order(pending_fetches::created_at.asc())
+            .load::<PendingFetch>(&mut self.connection)
+    }
+
+    pub fn remove_pending_fetch(&mut self, problem: i32) -> Result<(), diesel::result::Error> {
+        diesel::delete(pending_fetches::table.filter(pending_fetches::problem.eq(problem)))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    pub fn save_daily_problem(&mut self, daily: &DailyProblem) -> Result<(), diesel::result::Error> {
+        diesel::insert_into(daily_problems::table)
+            .values(daily)
+            .on_conflict((daily_problems::date, daily_problems::salt))
+            .do_update()
+            .set((
+                daily_problems::judge.eq(&daily.judge),
+                daily_problems::problem_id.eq(&daily.problem_id),
+                daily_problems::name.eq(&daily.name),
+                daily_problems::attempt.eq(daily.attempt),
+            ))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    pub fn get_daily_problems(&mut self, salt: &str) -> Result<Vec<DailyProblem>, diesel::result::Error> {
+        daily_problems::table
+            .filter(daily_problems::salt.eq(salt))
+            .order(daily_problems::date.desc())
+            .load::<DailyProblem>(&mut self.connection)
+    }
+
+    /// Задачу показано з назвою; теми та складність оновлюються, лише якщо відомі.
+    pub fn record_shown(&mut self, judge: &str, problem_id: &str, name: &str, tags: &str, difficulty: Option<i32>) -> Result<(), diesel::result::Error> {
+        let now = chrono::Utc::now().naive_utc();
+
+        diesel::insert_into(problem_history::table)
+            .values((
+                problem_history::judge.eq(judge),
+                problem_history::problem_id.eq(problem_id),
+                problem_history::name.eq(name),
+                problem_history::tags.eq(tags),
+                problem_history::difficulty.eq(difficulty),
+                problem_history::shown.eq(1),
+                problem_history::last_shown_at.eq(now),
+            ))
+            .on_conflict((problem_history::judge, problem_history::problem_id))
+            .do_update()
+            .set((
+                problem_history::name.eq(name),
+                problem_history::shown.eq(problem_history::shown + 1),
+                problem_history::last_shown_at.eq(now),
+            ))
+            .execute(&mut self.connection)?;
+
+        if !tags.is_empty() {
+            diesel::update(problem_history::table.find((judge, problem_id)))
+                .set(problem_history::tags.eq(tags))
+                .execute(&mut self.connection)?;
+        }
+        // Оцінку складності далі уточнює рейтинг, тож рейтинг із сайту береться лише як початкова
+        if difficulty.is_some() {
+            diesel::update(problem_history::table.find((judge, problem_id)).filter(problem_history::difficulty.is_null()))
+                .set(problem_history::difficulty.eq(difficulty))
+                .execute(&mut self.connection)?;
+        }
+
+        Ok(())
+    }
+
+    /// Задачу відкрили чи зберегли — вона вважається розв'язуваною, а не пропущеною.
+    pub fn record_opened(&mut self, judge: &str, problem_id: &str) -> Result<(), diesel::result::Error> {
+        diesel::update(problem_history::table.find((judge, problem_id)))
+            .set(problem_history::opened.eq(problem_history::opened + 1))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    /// Записує звіт і зберігає нову оцінку складності задачі в історії.
+    pub fn record_rating_change(&mut self, change: &RatingChange) -> Result<(), diesel::result::Error> {
+        self.connection.transaction(|conn| {
+            diesel::insert_into(rating_history::table)
+                .values(change)
+                .execute(conn)?;
+
+            diesel::insert_into(problem_history::table)
+                .values((
+                    problem_history::judge.eq(&change.judge),
+                    problem_history::problem_id.eq(&change.problem_id),
+                    problem_history::opened.eq(1),
+                    problem_history::last_shown_at.eq(change.created_at),
+                ))
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+            diesel::update(problem_history::table.find((&change.judge, &change.problem_id)))
+                .set(problem_history::difficulty.eq(change.difficulty_after.round() as i32))
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    pub fn get_rating_history(&mut self) -> Result<Vec<RatingChange>, diesel::result::Error> {
+        rating_history::table
+            .order(rating_history::id.asc())
+            .load::<RatingChange>(&mut self.connection)
+    }
+
+    /// Ставить задачу на повторення, якщо її там ще немає; відновлена з кошика зберігає свій розклад.
+    pub fn schedule_review(&mut self, review: &Review) -> Result<(), diesel::result::Error> {
+        diesel::insert_into(reviews::table)
+            .values(review)
+            .on_conflict_do_nothing()
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    pub fn record_review(&mut self, review: &Review) -> Result<(), diesel::result::Error> {
+        self.connection.transaction(|conn| {
+            diesel::insert_into(reviews::table)
+                .values(review)
+                .on_conflict(reviews::problem)
+                .do_update()
+                .set(review)
+                .execute(conn)?;
+
+            diesel::insert_into(review_log::table)
+                .values((
+                    review_log::problem.eq(review.problem),
+                    review_log::grade.eq(review.last_grade.as_deref().unwrap_or_default()),
+                    review_log::interval_days.eq(review.interval_days),
+                    review_log::reviewed_at.eq(review.reviewed_at.unwrap_or_else(|| chrono::Utc::now().naive_utc())),
+                ))
+                .execute(conn)?;
+
+            Ok(())
+        })
+    }
+
+    pub fn get_reviews(&mut self) -> Result<Vec<Review>, diesel::result::Error> {
+        reviews::table
+            .order(reviews::due.asc())
+            .load::<Review>(&mut self.connection)
+    }
+
+    /// Повертає id нового контесту; номер контесту в задачах підставляється тут.
+    pub fn start_contest(&mut self, started_at: NaiveDateTime, duration_minutes: i32, problems: &[ContestProblem]) -> Result<i32, diesel::result::Error> {
+        self.connection.transaction(|conn| {
+            diesel::insert_into(contests::table)
+                .values((
+                    contests::started_at.eq(started_at),
+                    contests::duration_minutes.eq(duration_minutes),
+                ))
+                .execute(conn)?;
+
+            let id = contests::table
+                .select(contests::id)
+                .order(contests::id.desc())
+                .first::<i32>(conn)?;
+
+            let problems = problems
+                .iter()
+                .map(|problem| ContestProblem { contest: id, ..problem.clone() })
+                .collect::<Vec<_>>();
+            diesel::insert_into(contest_problems::table)
+                .values(&problems)
+                .execute(conn)?;
+
+            Ok(id)
+        })
+    }
+
+    pub fn update_contest_problem(&mut self, problem: &ContestProblem) -> Result<(), diesel::result::Error> {
+        diesel::update(contest_problems::table.find((problem.contest, problem.position)))
+            .set((
+                contest_problems::attempts.eq(problem.attempts),
+                contest_problems::solved_minute.eq(problem.solved_minute),
+            ))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    pub fn finish_contest(&mut self, id: i32, finished_at: NaiveDateTime) -> Result<(), diesel::result::Error> {
+        diesel::update(contests::table.find(id))
+            .set(contests::finished_at.eq(finished_at))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    pub fn get_contests(&mut self) -> Result<Vec<Contest>, diesel::result::Error> {
+        contests::table
+            .order(contests::id.desc())
+            .load::<Contest>(&mut self.connection)
+    }
+
+    pub fn get_contest_problems(&mut self) -> Result<Vec<ContestProblem>, diesel::result::Error> {
+        contest_problems::table
+            .order((contest_problems::contest.asc(), contest_problems::position.asc()))
+            .load::<ContestProblem>(&mut self.connection)
+    }
+
+    /// Повертає id нової сесії.
+    pub fn start_session(&mut self, judge: &str, problem_id: &str, started_at: NaiveDateTime) -> Result<i32, diesel::result::Error> {
+        self.connection.transaction(|conn| {
+            diesel::insert_into(work_sessions::table)
+                .values((
+                    work_sessions::judge.eq(judge),
+                    work_sessions::problem_id.eq(problem_id),
+                    work_sessions::started_at.eq(started_at),
+                ))
+                .execute(conn)?;
+
+            work_sessions::table
+                .select(work_sessions::id)
+                .order(work_sessions::id.desc())
+                .first::<i32>(conn)
+        })
+    }
+
+    pub fn end_session(&mut self, id: i32, ended_at: NaiveDateTime) -> Result<(), diesel::result::Error> {
+        diesel::update(work_sessions::table.find(id))
+            .set(work_sessions::ended_at.eq(ended_at))
             .execute(&mut self.connection)?;
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Сесія, яку не встигли зупинити до закриття застосунку.
+    pub fn get_open_session(&mut self) -> Result<Option<OpenSession>, diesel::result::Error> {
+        work_sessions::table
+            .filter(work_sessions::ended_at.is_null())
+            .select((work_sessions::id, work_sessions::judge, work_sessions::problem_id, work_sessions::started_at))
+            .order(work_sessions::id.desc())
+            .first::<OpenSession>(&mut self.connection)
+            .optional()
+    }
+
+    pub fn get_time_per_problem(&mut self) -> Result<Vec<ProblemTime>, diesel::result::Error> {
+        diesel::sql_query(
+            "SELECT judge, problem_id,
+                CAST(ROUND(SUM(julianday(ended_at) - julianday(started_at)) * 86400) AS INTEGER) AS seconds
+            FROM work_sessions
+            WHERE ended_at IS NOT NULL
+            GROUP BY judge, problem_id"
+        )
+            .load::<ProblemTime>(&mut self.connection)
+    }
+
+    /// Завершені проміжки роботи, що закінчилися після `since`: (початок, кінець).
+    pub fn get_sessions_since(&mut self, since: NaiveDateTime) -> Result<Vec<(NaiveDateTime, NaiveDateTime)>, diesel::result::Error> {
+        work_sessions::table
+            .filter(work_sessions::ended_at.gt(since))
+            .select((work_sessions::started_at, work_sessions::ended_at.assume_not_null()))
+            .order(work_sessions::started_at.asc())
+            .load::<(NaiveDateTime, NaiveDateTime)>(&mut self.connection)
+    }
+
+    /// Кількість різних задач, розв'язаних за кожен з останніх `days` днів.
+    pub fn get_solved_per_day(&mut self, days: i64) -> Result<Vec<DayCount>, diesel::result::Error> {
+        diesel::sql_query(format!(
+            "SELECT date(solved_at, 'localtime') AS day, COUNT(DISTINCT judge || '/' || problem_id) AS count
+            FROM ({SOLVES})
+            WHERE date(solved_at, 'localtime') > date('now', 'localtime', ?)
+            GROUP BY day
+            ORDER BY day"
+        ))
+            .bind::<Text, _>(format!("-{} days", days))
+            .load::<DayCount>(&mut self.connection)
+    }
+
+    /// Те саме за тижнями; `day` — понеділок тижня.
+    pub fn get_solved_per_week(&mut self, weeks: i64) -> Result<Vec<DayCount>, diesel::result::Error> {
+        diesel::sql_query(format!(
+            "SELECT date(solved_at, 'localtime', 'weekday 0', '-6 days') AS day,
+                COUNT(DISTINCT judge || '/' || problem_id) AS count
+            FROM ({SOLVES})
+            WHERE date(solved_at, 'localtime') > date('now', 'localtime', ?)
+            GROUP BY day
+            ORDER BY day"
+        ))
+            .bind::<Text, _>(format!("-{} days", weeks * 7))
+            .load::<DayCount>(&mut self.connection)
+    }
+
+    /// Розв'язані задачі з відомою складністю, згруповані в кошики ширини `width`.
+    pub fn get_solved_by_difficulty(&mut self, width: i32) -> Result<Vec<BucketCount>, diesel::result::Error> {
+        diesel::sql_query(format!(
+            "SELECT (h.difficulty / ?) * ? AS bucket, COUNT(*) AS count
+            FROM (SELECT DISTINCT judge, problem_id FROM ({SOLVES})) s
+            JOIN problem_history h ON h.judge = s.judge AND h.problem_id = s.problem_id
+            WHERE h.difficulty IS NOT NULL
+            GROUP BY bucket
+            ORDER BY bucket"
+        ))
+            .bind::<Integer, _>(width)
+            .bind::<Integer, _>(width)
+            .load::<BucketCount>(&mut self.connection)
+    }
+
+    /// Найчастіші теми розв'язаних задач; теми розбиваються з рядка через кому рекурсивним запитом.
+    pub fn get_solved_by_tag(&mut self, limit: i64) -> Result<Vec<TagCount>, diesel::result::Error> {
+        diesel::sql_query(format!(
+            "WITH RECURSIVE split(tag, rest) AS (
+                SELECT '', h.tags || ','
+                FROM (SELECT DISTINCT judge, problem_id FROM ({SOLVES})) s
+                JOIN problem_history h ON h.judge = s.judge AND h.problem_id = s.problem_id
+                UNION ALL
+                SELECT trim(substr(rest, 1, instr(rest, ',') - 1)), substr(rest, instr(rest, ',') + 1)
+                FROM split
+                WHERE rest <> ''
+            )
+            SELECT tag, COUNT(*) AS count
+            FROM split
+            WHERE tag <> ''
+            GROUP BY tag
+            ORDER BY count DESC, tag
+            LIMIT ?"
+        ))
+            .bind::<BigInt, _>(limit)
+            .load::<TagCount>(&mut self.connection)
+    }
+
+    /// Середній час береться зі звітів, де вказано хвилини (чи їх підставив таймер).
+    pub fn get_solve_summary(&mut self) -> Result<SolveSummary, diesel::result::Error> {
+        diesel::sql_query(format!(
+            "WITH solved AS (SELECT DISTINCT judge, problem_id FROM ({SOLVES}))
+            SELECT
+                (SELECT COUNT(*) FROM solved) AS solved,
+                (SELECT COUNT(*) FROM problems WHERE deleted_at IS NULL) AS saved,
+                (SELECT COUNT(*) FROM problems p JOIN solved s ON s.judge = p.judge AND s.problem_id = p.problem_id
+                    WHERE p.deleted_at IS NULL) AS saved_solved,
+                (SELECT AVG(minutes) FROM rating_history WHERE solved AND minutes IS NOT NULL) AS average_minutes,
+                (SELECT COUNT(*) FROM rating_history WHERE solved AND minutes IS NOT NULL) AS timed"
+        ))
+            .get_result::<SolveSummary>(&mut self.connection)
+    }
+
+    pub fn get_history(&mut self) -> Result<Vec<HistoryEntry>, diesel::result::Error> {
+        problem_history::table
+            .order(problem_history::last_shown_at.desc())
+            .load::<HistoryEntry>(&mut self.connection)
+    }
+
+    pub fn get_setting(&mut self, key: &str) -> Result<Option<String>, diesel::result::Error> {
+        settings::table
+            .filter(settings::key.eq(key))
+            .select(settings::value)
+            .first::<String>(&mut self.connection)
+            .optional()
+    }
+
+    pub fn set_setting(&mut self, key: &str, value: &str) -> Result<(), diesel::result::Error> {
+        diesel::insert_into(settings::table)
+            .values((settings::key.eq(key), settings::value.eq(value)))
+            .on_conflict(settings::key)
+            .do_update()
+            .set(settings::value.eq(value))
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+
+    pub fn log_error(&mut self, severity: &str, message: &str, details: Option<&str>) -> Result<(), diesel::result::Error> {
+        diesel::insert_into(error_log::table)
+            .values((
+                error_log::severity.eq(severity),
+                error_log::message.eq(message),
+                error_log::details.eq(details),
+                error_log::created_at.eq(chrono::Utc::now().naive_utc()),
+            ))
+            .execute(&mut self.connection)?;
+
+        let oldest_kept = error_log::table
+            .select(error_log::id)
+            .order(error_log::id.desc())
+            .offset(ERROR_LOG_LIMIT - 1)
+            .first::<i32>(&mut self.connection)
+            .optional()?;
+
+        if let Some(oldest_kept) = oldest_kept {
+            diesel::delete(error_log::table.filter(error_log::id.lt(oldest_kept)))
+                .execute(&mut self.connection)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_error_log(&mut self) -> Result<Vec<LogEntry>, diesel::result::Error> {
+        error_log::table
+            .select((error_log::severity, error_log::message, error_log::details, error_log::created_at))
+            .order(error_log::id.desc())
+            .load::<LogEntry>(&mut self.connection)
+    }
+
+    pub fn clear_error_log(&mut self) -> Result<(), diesel::result::Error> {
+        diesel::delete(error_log::table)
+            .execute(&mut self.connection)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{Duration, Local};
+
+    use super::*;
+    use crate::stats;
+
+    fn database() -> Database {
+        Database::open(":memory:").unwrap()
+    }
+
+    // Дата в місцевому часовому поясі, як її рахує `date(..., 'localtime')`
+    fn local_day(at: NaiveDateTime) -> NaiveDate {
+        at.and_utc().with_timezone(&Local).date_naive()
+    }
+
+    fn report(db: &mut Database, problem_id: &str, solved: bool, minutes: Option<i32>, difficulty: f64, at: NaiveDateTime) {
+        db.record_rating_change(&RatingChange {
+            id: 0,
+            judge: "codeforces".to_string(),
+            problem_id: problem_id.to_string(),
+            solved,
+            minutes,
+            rating_before: 1500.0,
+            rating_after: 1500.0,
+            difficulty_before: difficulty,
+            difficulty_after: difficulty,
+            created_at: at,
+        })
+            .unwrap();
+    }
+
+    fn contest_solve(db: &mut Database, problem_id: &str, started_at: NaiveDateTime, minute: i32) {
+        let problem = ContestProblem {
+            contest: 0,
+            position: 0,
+            judge: "codeforces".to_string(),
+            problem_id: problem_id.to_string(),
+            name: String::new(),
+            url: String::new(),
+            attempts: 0,
+            solved_minute: None,
+        };
+        let contest = db.start_contest(started_at, 120, std::slice::from_ref(&problem)).unwrap();
+        db.update_contest_problem(&ContestProblem { contest, solved_minute: Some(minute), ..problem }).unwrap();
+    }
+
+    // Розв'язки за різні дні: повторний звіт про ту саму задачу, нерозв'язана задача,
+    // задача з контесту та давня задача за межами вікна
+    fn solves(db: &mut Database) -> Vec<NaiveDateTime> {
+        let now = chrono::Utc::now().naive_utc();
+        let contest_start = now - Duration::days(1) - Duration::hours(2);
+        // 1A, 2B, 5E з контесту та 4D
+        let solved = vec![
+            now,
+            now - Duration::days(2),
+            contest_start + Duration::minutes(30),
+            now - Duration::days(40),
+        ];
+
+        db.record_shown("codeforces", "1A", "", "dp, greedy", None).unwrap();
+        db.record_shown("codeforces", "2B", "", "greedy", None).unwrap();
+        db.record_shown("codeforces", "3C", "", "dp", None).unwrap();
+        db.record_shown("codeforces", "5E", "", "math,greedy", Some(810)).unwrap();
+
+        report(db, "1A", true, Some(30), 1250.0, now);
+        report(db, "1A", true, Some(60), 1250.0, now);
+        report(db, "2B", true, None, 1390.0, now - Duration::days(2));
+        report(db, "3C", false, Some(10), 800.0, now);
+        report(db, "4D", true, Some(90), 1610.0, now - Duration::days(40));
+        contest_solve(db, "5E", contest_start, 30);
+
+        solved
+    }
+
+    #[test]
+    fn solved_problems_are_counted_per_day_and_week() {
+        let mut db = database();
+        let solved = solves(&mut db);
+
+        let mut expected_days = BTreeMap::new();
+        let mut expected_weeks = BTreeMap::new();
+        for at in &solved[..3] {
+            *expected_days.entry(local_day(*at)).or_insert(0) += 1;
+        }
+        for at in &solved {
+            let week = stats::week_start(local_day(*at));
+            *expected_weeks.entry(week).or_insert(0) += 1;
+        }
+
+        let per_day = db.get_solved_per_day(stats::DAYS_SHOWN as i64).unwrap();
+        let per_day = per_day.into_iter().map(|entry| (entry.day, entry.count)).collect::<BTreeMap<_, _>>();
+        assert_eq!(per_day, expected_days);
+
+        // 40 днів тому потрапляє у вікно з 12 тижнів; тижні починаються з понеділка
+        let per_week = db.get_solved_per_week(stats::WEEKS_SHOWN as i64).unwrap();
+        let per_week = per_week.into_iter().map(|entry| (entry.day, entry.count)).collect::<BTreeMap<_, _>>();
+        assert_eq!(per_week, expected_weeks);
+        assert!(per_week.keys().all(|day| stats::week_start(*day) == *day));
+    }
+
+    #[test]
+    fn solved_problems_are_grouped_by_difficulty_and_tag() {
+        let mut db = database();
+        solves(&mut db);
+
+        let buckets = db.get_solved_by_difficulty(stats::DIFFICULTY_BUCKET).unwrap();
+        let buckets = buckets.into_iter().map(|entry| (entry.bucket, entry.count)).collect::<Vec<_>>();
+        assert_eq!(buckets, vec![(800, 1), (1200, 2), (1600, 1)]);
+
+        // Теми нерозв'язаної 3C не враховуються, пробіли після ком відкидаються
+        let tags = db.get_solved_by_tag(stats::TAGS_SHOWN).unwrap();
+        let tags = tags.into_iter().map(|entry| (entry.tag, entry.count)).collect::<Vec<_>>();
+        assert_eq!(tags, vec![("greedy".to_string(), 3), ("dp".to_string(), 1), ("math".to_string(), 1)]);
+
+        let top = db.get_solved_by_tag(1).unwrap();
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].tag, "greedy");
+    }
+
+    #[test]
+    fn reviews_are_seeded_only_for_saved_problems() {
+        let mut db = database();
+        let kept = db.save_problem("eolymp", "1", String::new(), String::new(), "uk").unwrap();
+        let trashed = db.save_problem("eolymp", "2", String::new(), String::new(), "uk").unwrap();
+        db.delete_problem(trashed).unwrap();
+
+        // Повертаємо базу до стану перед міграцією повторень і проганяємо її заново
+        db.connection.batch_execute("DROP TABLE reviews; DROP TABLE review_log; PRAGMA user_version = 9;").unwrap();
+        Database::migrate(&mut db.connection).unwrap();
+
+        let reviews = db.get_reviews().unwrap();
+        assert_eq!(reviews.iter().map(|review| review.problem).collect::<Vec<_>>(), vec![kept]);
+    }
+
+    #[test]
+    fn purge_removes_dependent_rows() {
+        let mut db = database();
+        let today = chrono::Local::now().date_naive();
+        let mut ids = Vec::new();
+        for problem_id in ["1", "2", "3"] {
+            let id = db.save_problem("eolymp", problem_id, String::new(), String::new(), "uk").unwrap();
+            let problem = db.get_all_problems().unwrap().into_iter().find(|problem| problem.id == id).unwrap();
+            db.enqueue_fetch(&problem, "https://eolymp.com/uk/problems/1", "uk").unwrap();
+            db.schedule_review(&crate::review::initial(id, today)).unwrap();
+            ids.push(id);
+        }
+
+        db.delete_problem(ids[0]).unwrap();
+        db.purge_problem(ids[0]).unwrap();
+        // Задачу не в кошику остаточно не видалити
+        db.purge_problem(ids[1]).unwrap();
+        db.delete_problem(ids[2]).unwrap();
+        db.purge_deleted_problems().unwrap();
+
+        let remaining = vec![ids[1]];
+        assert_eq!(db.get_all_problems().unwrap().iter().map(|problem| problem.id).collect::<Vec<_>>(), remaining);
+        assert!(db.get_deleted_problems().unwrap().is_empty());
+        assert_eq!(db.get_pending_fetches().unwrap().iter().map(|pending| pending.problem).collect::<Vec<_>>(), remaining);
+        assert_eq!(db.get_reviews().unwrap().iter().map(|review| review.problem).collect::<Vec<_>>(), remaining);
+    }
+
+    #[test]
+    fn changing_locale_drops_the_old_title() {
+        let mut db = database();
+        let id = db.save_problem("eolymp", "1", "A + B".to_string(), "https://eolymp.com/uk/problems/1".to_string(), "uk").unwrap();
+
+        db.set_problem_locale(id, "en", "https://eolymp.com/en/problems/1".to_string()).unwrap();
+        let problem = db.get_all_problems().unwrap().remove(0);
+        assert_eq!(problem.locale.as_deref(), Some("en"));
+        assert_eq!(problem.url, "https://eolymp.com/en/problems/1");
+        assert!(problem.name.is_empty());
+    }
+
+    #[test]
+    fn summary_counts_saved_and_timed_solves() {
+        let mut db = database();
+        assert_eq!(db.get_solve_summary().unwrap().average_minutes, None);
+
+        solves(&mut db);
+        db.save_problem("codeforces", "1A", String::new(), String::new(), "en").unwrap();
+        db.save_problem("codeforces", "3C", String::new(), String::new(), "en").unwrap();
+        let deleted = db.save_problem("codeforces", "2B", String::new(), String::new(), "en").unwrap();
+        db.delete_problem(deleted).unwrap();
+
+        let summary = db.get_solve_summary().unwrap();
+        assert_eq!(summary.solved, 4);
+        assert_eq!(summary.saved, 2);
+        assert_eq!(summary.saved_solved, 1);
+        assert_eq!(summary.timed, 3);
+        assert_eq!(summary.average_minutes, Some(60.0));
+    }
+}
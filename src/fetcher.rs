@@ -0,0 +1,232 @@
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+use std::sync::mpsc;
+
+use eframe::egui;
+use tokio::runtime::Runtime;
+use tokio::task::AbortHandle;
+
+use crate::api;
+use crate::http::{HttpClient, HttpConfig};
+use crate::judge::Judge;
+use crate::locale::Locale;
+use crate::title::{self, ProblemMeta};
+
+/// Кожен запит назви має власний номер, щоб відрізнити застарілі відповіді.
+pub type RequestId = u64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FetchError {
+    Network(String),
+    Timeout,
+    Status(u16),
+    NoTitle,
+    NotFound,
+    ErrorPage(String),
+    LoginRequired,
+    Api(String),
+    Record(String),
+    Replay(String),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Network(e) => write!(f, "network error: {}", e),
+            FetchError::Timeout => write!(f, "request timed out"),
+            FetchError::Status(code) => write!(f, "HTTP {}", code),
+            FetchError::NoTitle => write!(f, "the page has no title"),
+            FetchError::NotFound => write!(f, "no such problem"),
+            FetchError::ErrorPage(title) => write!(f, "the site returned an error page: {}", title),
+            FetchError::LoginRequired => write!(f, "the page requires signing in"),
+            FetchError::Api(e) => write!(f, "API error: {}", e),
+            FetchError::Record(e) => write!(f, "could not record the response: {}", e),
+            FetchError::Replay(e) => write!(f, "could not replay the response: {}", e),
+        }
+    }
+}
+
+/// Звідки брати назви задач: зі сторінки сайту чи з GraphQL API.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum TitleSource {
+    #[default]
+    Html,
+    Api,
+}
+
+impl TitleSource {
+    pub const ALL: [TitleSource; 2] = [TitleSource::Html, TitleSource::Api];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            TitleSource::Html => "html",
+            TitleSource::Api => "api",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|source| source.code() == code)
+    }
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            TitleSource::Html => "source.html",
+            TitleSource::Api => "source.api",
+        }
+    }
+}
+
+pub struct TitleResponse {
+    pub request_id: RequestId,
+    pub result: Result<String, FetchError>,
+    pub meta: ProblemMeta,
+}
+
+/// Дозволяє перервати запит, що ще виконується.
+pub struct CancelToken(AbortHandle);
+
+impl CancelToken {
+    pub fn cancel(&self) {
+        self.0.abort();
+    }
+}
+
+/// Виконує мережеві запити на фоновому tokio-рантаймі та будить UI, коли приходить відповідь.
+pub struct Fetcher {
+    runtime: Runtime,
+    client: Arc<HttpClient>,
+    source: TitleSource,
+    api_url: Arc<str>,
+    tx: mpsc::Sender<TitleResponse>,
+    rx: mpsc::Receiver<TitleResponse>,
+    ctx: egui::Context,
+}
+
+impl Fetcher {
+    pub fn new(ctx: egui::Context) -> io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .thread_name("eolymp-fetcher")
+            .enable_all()
+            .build()?;
+        let client = HttpClient::new(HttpConfig::default()).map_err(io::Error::other)?;
+        let (tx, rx) = mpsc::channel();
+
+        Ok(Self {
+            runtime,
+            client: Arc::new(client),
+            source: TitleSource::default(),
+            api_url: Arc::from(api::DEFAULT_ENDPOINT),
+            tx,
+            rx,
+            ctx,
+        })
+    }
+
+    /// Перебудовує клієнт; запити, що вже виконуються, доробляють зі старими налаштуваннями.
+    pub fn configure(&mut self, config: HttpConfig) -> Result<(), reqwest::Error> {
+        self.client = Arc::new(HttpClient::new(config)?);
+        Ok(())
+    }
+
+    pub fn source(&self) -> TitleSource {
+        self.source
+    }
+
+    pub fn set_source(&mut self, source: TitleSource) {
+        self.source = source;
+    }
+
+    pub fn set_api_url(&mut self, url: String) {
+        self.api_url = Arc::from(url);
+    }
+
+    pub fn cache_size(&self) -> u64 {
+        self.client.cache().size()
+    }
+
+    pub fn clear_cache(&self) -> io::Result<()> {
+        self.client.cache().clear()
+    }
+
+    pub fn fetch_title(&self, request_id: RequestId, judge: Judge, problem_id: String, url: String, locale: Locale) -> CancelToken {
+        let client = Arc::clone(&self.client);
+        let source = self.source;
+        let api_url = Arc::clone(&self.api_url);
+        let tx = self.tx.clone();
+        let ctx = self.ctx.clone();
+
+        let handle = self.runtime.spawn(async move {
+            // API є лише в eolymp, для інших суддів завжди розбираємо сторінку
+            let api_id = problem_id.parse::<u32>().ok().filter(|_| judge == Judge::Eolymp);
+            let (result, meta) = match (source, api_id) {
+                (TitleSource::Api, Some(id)) => (api::problem_title(&client, &api_url, id, locale).await, ProblemMeta::default()),
+                _ => match get_problem_page(&client, judge, &url, locale).await {
+                    Ok((title, meta)) => (Ok(title), meta),
+                    Err(e) => (Err(e), ProblemMeta::default()),
+                },
+            };
+            let _ = tx.send(TitleResponse { request_id, result, meta });
+            ctx.request_repaint();
+        });
+
+        CancelToken(handle.abort_handle())
+    }
+
+    /// Відповідь на перевірку зв'язку приходить як `TitleResponse` з порожнім `Ok`.
+    pub fn check_connection(&self, request_id: RequestId, url: String) -> CancelToken {
+        let client = Arc::clone(&self.client);
+        let tx = self.tx.clone();
+        let ctx = self.ctx.clone();
+
+        let handle = self.runtime.spawn(async move {
+            let result = client.probe(&url).await.map(|_| String::new());
+            let _ = tx.send(TitleResponse { request_id, result, meta: ProblemMeta::default() });
+            ctx.request_repaint();
+        });
+
+        CancelToken(handle.abort_handle())
+    }
+
+    pub fn try_recv(&self) -> Option<TitleResponse> {
+        self.rx.try_recv().ok()
+    }
+}
+
+async fn get_problem_page(client: &HttpClient, judge: Judge, url: &str, locale: Locale) -> Result<(String, ProblemMeta), FetchError> {
+    let html = client.get_text(url, locale.code()).await?;
+    let title = title::extract_title(judge, &html)?;
+    Ok((title, title::extract_meta(judge, &html)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::transport::ReplayTransport;
+
+    #[test]
+    fn fetches_title_from_recorded_page() {
+        let root = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let config = HttpConfig {
+            cache_dir: std::env::temp_dir().join(format!("eolymp-fetcher-{}", std::process::id())),
+            max_retries: 0,
+            ..HttpConfig::default()
+        };
+        let client = HttpClient::with_transport(
+            Arc::new(ReplayTransport::new(root.join("tests/fixtures/recorded"))),
+            config.clone(),
+        );
+
+        let title = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(get_problem_page(&client, Judge::Eolymp, "https://eolymp.com/en/problems/1", Locale::En))
+            .map(|(title, _)| title);
+
+        assert_eq!(title, Ok("A + B".to_string()));
+        let _ = std::fs::remove_dir_all(config.cache_dir);
+    }
+}
@@ -0,0 +1,329 @@
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::StatusCode;
+use reqwest::header::{ACCEPT_LANGUAGE, CONTENT_TYPE, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+
+use crate::cache::{self, CacheMeta, PageCache};
+use crate::fetcher::FetchError;
+use crate::transport::{Method, ReqwestTransport, Request, Transport, TransportMode};
+
+pub const DEFAULT_USER_AGENT: &str = concat!("eolymp-problem-generator/", env!("CARGO_PKG_VERSION"));
+pub const MAX_RETRIES: u32 = 10;
+
+// Пауза між повторами подвоюється, але не перевищує цієї межі
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Параметри спільного HTTP-клієнта; зберігаються в налаштуваннях користувача.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpConfig {
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    pub max_retries: u32,
+    pub backoff_base: Duration,
+    pub user_agent: String,
+    pub proxy: Option<String>,
+    pub cache_ttl: Duration,
+    pub cache_max_bytes: u64,
+    pub cache_dir: PathBuf,
+    pub transport: TransportMode,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            connect_timeout: Duration::from_secs(5),
+            max_retries: 3,
+            backoff_base: Duration::from_millis(500),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            proxy: None,
+            cache_ttl: Duration::from_secs(24 * 60 * 60),
+            cache_max_bytes: 50 * 1024 * 1024,
+            cache_dir: PathBuf::from(cache::DEFAULT_DIR),
+            transport: TransportMode::default(),
+        }
+    }
+}
+
+/// Спільний клієнт із повторами та кешем; самі запити виконує підмінний `Transport`.
+pub struct HttpClient {
+    transport: Arc<dyn Transport>,
+    cache: PageCache,
+    config: HttpConfig,
+}
+
+enum Fetched {
+    Body {
+        text: String,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+    NotModified,
+}
+
+impl HttpClient {
+    pub fn new(config: HttpConfig) -> Result<Self, reqwest::Error> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .connect_timeout(config.connect_timeout)
+            .user_agent(&config.user_agent)
+            .pool_idle_timeout(Duration::from_secs(90));
+
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+
+        let live = Arc::new(ReqwestTransport::new(builder.build()?));
+        Ok(Self::with_transport(config.transport.wrap(live), config))
+    }
+
+    pub fn with_transport(transport: Arc<dyn Transport>, config: HttpConfig) -> Self {
+        Self {
+            transport,
+            cache: PageCache::new(config.cache_dir.clone(), config.cache_max_bytes),
+            config,
+        }
+    }
+
+    pub fn cache(&self) -> &PageCache {
+        &self.cache
+    }
+
+    /// GET-запит через кеш: свіжа сторінка береться з диска, застаріла перевіряється
+    /// на сервері за ETag/Last-Modified, а тимчасові збої повторюються з експоненційною затримкою.
+    pub async fn get_text(&self, url: &str, accept_language: &str) -> Result<String, FetchError> {
+        let cached = self.cache.get(url);
+        if let Some(page) = &cached
+            && page.is_fresh(self.config.cache_ttl)
+        {
+            return Ok(page.body.clone());
+        }
+
+        let meta = cached.as_ref().map(|page| &page.meta);
+        match self.with_retries(|| self.try_get(url, accept_language, meta)).await {
+            Ok(Fetched::Body { text, etag, last_modified }) => {
+                let _ = self.cache.put(url, etag, last_modified, &text);
+                Ok(text)
+            }
+            Ok(Fetched::NotModified) => {
+                let _ = self.cache.touch(url);
+                cached.map(|page| page.body).ok_or(FetchError::Status(StatusCode::NOT_MODIFIED.as_u16()))
+            }
+            // Без мережі застаріла копія краща, ніж нічого
+            Err(e) if e.is_transient() => cached.map(|page| page.body).ok_or(e),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Перевіряє, чи відповідає сервер; кеш тут не використовується.
+    pub async fn probe(&self, url: &str) -> Result<(), FetchError> {
+        self.transport.send(Request::new(Method::Head, url)).await?;
+        Ok(())
+    }
+
+    /// POST-запит із JSON-тілом для API; відповіді не кешуються.
+    pub async fn post_json(&self, url: &str, accept_language: &str, body: String) -> Result<String, FetchError> {
+        self.with_retries(|| async {
+            let request = Request::new(Method::Post, url)
+                .header(ACCEPT_LANGUAGE.as_str(), accept_language)
+                .header(CONTENT_TYPE.as_str(), "application/json")
+                .body(body.clone());
+            let response = self.transport.send(request).await?;
+
+            if !StatusCode::from_u16(response.status).is_ok_and(|status| status.is_success()) {
+                return Err(FetchError::Status(response.status));
+            }
+
+            Ok(response.body)
+        })
+            .await
+    }
+
+    async fn with_retries<T, F, Fut>(&self, mut request: F) -> Result<T, FetchError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, FetchError>>,
+    {
+        let mut attempt = 0;
+
+        loop {
+            match request().await {
+                Err(e) if e.is_transient() && attempt < self.config.max_retries => {
+                    tokio::time::sleep(backoff(self.config.backoff_base, attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn try_get(&self, url: &str, accept_language: &str, cached: Option<&CacheMeta>) -> Result<Fetched, FetchError> {
+        let mut request = Request::new(Method::Get, url)
+            .header(ACCEPT_LANGUAGE.as_str(), accept_language);
+
+        if let Some(meta) = cached {
+            if let Some(etag) = &meta.etag {
+                request = request.header(IF_NONE_MATCH.as_str(), etag);
+            }
+            if let Some(last_modified) = &meta.last_modified {
+                request = request.header(IF_MODIFIED_SINCE.as_str(), last_modified);
+            }
+        }
+
+        let response = self.transport.send(request).await?;
+
+        if response.status == StatusCode::NOT_MODIFIED.as_u16() && cached.is_some() {
+            return Ok(Fetched::NotModified);
+        }
+        if !StatusCode::from_u16(response.status).is_ok_and(|status| status.is_success()) {
+            return Err(FetchError::Status(response.status));
+        }
+
+        Ok(Fetched::Body {
+            etag: response.header(ETAG.as_str()).map(str::to_string),
+            last_modified: response.header(LAST_MODIFIED.as_str()).map(str::to_string),
+            text: response.body,
+        })
+    }
+}
+
+/// Пауза перед повтором номер `attempt`, рахуючи з нуля.
+fn backoff(base: Duration, attempt: u32) -> Duration {
+    2u32.checked_pow(attempt)
+        .and_then(|factor| base.checked_mul(factor))
+        .map_or(MAX_BACKOFF, |delay| delay.min(MAX_BACKOFF))
+}
+
+impl From<reqwest::Error> for FetchError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            FetchError::Timeout
+        } else {
+            FetchError::Network(e.to_string())
+        }
+    }
+}
+
+impl FetchError {
+    // Повторювати має сенс лише ті помилки, що можуть минути самі
+    pub fn is_transient(&self) -> bool {
+        match self {
+            FetchError::Network(_) | FetchError::Timeout => true,
+            FetchError::Status(code) => {
+                *code == StatusCode::TOO_MANY_REQUESTS.as_u16() || *code >= 500
+            }
+            FetchError::NoTitle
+            | FetchError::NotFound
+            | FetchError::ErrorPage(_)
+            | FetchError::LoginRequired
+            | FetchError::Api(_)
+            | FetchError::Record(_)
+            | FetchError::Replay(_) => false,
+        }
+    }
+
+    /// Сервер не відповів зовсім. Будь-який код стану, навіть 429 чи 5xx,
+    /// означає, що зв'язок є.
+    pub fn is_offline(&self) -> bool {
+        matches!(self, FetchError::Network(_) | FetchError::Timeout)
+    }
+
+    /// Задачі з таким id немає. Судді повідомляють про це по-різному: сторінкою помилки,
+    /// кодом 404 чи переадресацією на сторінку без назви.
+    pub fn is_missing(&self) -> bool {
+        matches!(
+            self,
+            FetchError::NotFound | FetchError::ErrorPage(_) | FetchError::NoTitle | FetchError::Status(404)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::transport::{BoxFuture, Response};
+
+    // Віддає заготовлені відповіді по черзі й запам'ятовує заголовки запитів
+    struct ScriptedTransport {
+        responses: Mutex<Vec<Response>>,
+        requests: Mutex<Vec<Request>>,
+    }
+
+    impl Transport for ScriptedTransport {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, FetchError>> {
+            self.requests.lock().unwrap().push(request);
+            let response = self.responses.lock().unwrap().remove(0);
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let base = Duration::from_millis(500);
+        assert_eq!(backoff(base, 0), base);
+        assert_eq!(backoff(base, 3), Duration::from_secs(4));
+        assert_eq!(backoff(base, 10), MAX_BACKOFF);
+        // 2^40 не вміщається в u32, а множення на нього переповнило б Duration
+        assert_eq!(backoff(base, 40), MAX_BACKOFF);
+        assert_eq!(backoff(Duration::MAX, 1), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn only_unanswered_requests_mean_offline() {
+        assert!(FetchError::Network("connection refused".to_string()).is_offline());
+        assert!(FetchError::Timeout.is_offline());
+
+        for code in [404, 429, 503] {
+            assert!(!FetchError::Status(code).is_offline());
+        }
+        assert!(FetchError::Status(503).is_transient());
+        assert!(!FetchError::NotFound.is_offline());
+    }
+
+    #[test]
+    fn stale_page_is_revalidated_with_conditional_headers() {
+        let url = "https://eolymp.com/en/problems/1";
+        let config = HttpConfig {
+            cache_ttl: Duration::ZERO,
+            cache_dir: std::env::temp_dir().join(format!("eolymp-http-{}", std::process::id())),
+            ..HttpConfig::default()
+        };
+        let _ = std::fs::remove_dir_all(&config.cache_dir);
+        let transport = Arc::new(ScriptedTransport {
+            responses: Mutex::new(vec![
+                Response {
+                    status: 200,
+                    headers: BTreeMap::from([
+                        ("etag".to_string(), "\"v1\"".to_string()),
+                        ("last-modified".to_string(), "Mon, 03 Mar 2025 10:00:00 GMT".to_string()),
+                    ]),
+                    body: "<title>A + B</title>".to_string(),
+                },
+                Response { status: 304, ..Response::default() },
+            ]),
+            requests: Mutex::new(Vec::new()),
+        });
+        let client = HttpClient::with_transport(transport.clone(), config);
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+
+        assert_eq!(runtime.block_on(client.get_text(url, "en")).unwrap(), "<title>A + B</title>");
+        assert_eq!(runtime.block_on(client.get_text(url, "en")).unwrap(), "<title>A + B</title>");
+
+        let requests = transport.requests.lock().unwrap();
+        let header = |request: &Request, name: &str| {
+            request.headers.iter().find(|(key, _)| key == name).map(|(_, value)| value.clone())
+        };
+        assert_eq!(header(&requests[0], "if-none-match"), None);
+        assert_eq!(header(&requests[1], "if-none-match").as_deref(), Some("\"v1\""));
+        assert_eq!(header(&requests[1], "if-modified-since").as_deref(), Some("Mon, 03 Mar 2025 10:00:00 GMT"));
+        assert_eq!(client.cache().get(url).unwrap().meta.etag.as_deref(), Some("\"v1\""));
+
+        client.cache().clear().unwrap();
+    }
+}
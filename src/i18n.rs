@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+// Каталоги, які можна перевизначити файлами `i18n/<код>.lang` поруч із застосунком
+const OVERRIDE_DIR: &str = "i18n";
+
+const UK_CATALOG: &str = include_str!("../assets/i18n/uk.lang");
+const EN_CATALOG: &str = include_str!("../assets/i18n/en.lang");
+
+/// Мова інтерфейсу застосунку.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    Uk,
+    En,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Uk, Language::En];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Uk => "uk",
+            Language::En => "en",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::Uk => "Українська",
+            Language::En => "English",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|language| language.code() == code)
+    }
+
+    fn catalog(self) -> &'static str {
+        match self {
+            Language::Uk => UK_CATALOG,
+            Language::En => EN_CATALOG,
+        }
+    }
+}
+
+pub struct Translations {
+    language: Language,
+    strings: HashMap<String, String>,
+    fallback: HashMap<String, String>,
+}
+
+impl Translations {
+    pub fn new(language: Language) -> Self {
+        Self {
+            language,
+            strings: Self::load(language),
+            fallback: Self::load(Language::default()),
+        }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Повертає рядок за ключем; якщо перекладу немає — український варіант або сам ключ.
+    pub fn tr<'a>(&'a self, key: &'a str) -> &'a str {
+        self.strings
+            .get(key)
+            .or_else(|| self.fallback.get(key))
+            .map(String::as_str)
+            .unwrap_or(key)
+    }
+
+    /// Те саме, що й `tr`, але з підстановкою плейсхолдерів `{name}`.
+    pub fn tr_args(&self, key: &str, args: &[(&str, &dyn ToString)]) -> String {
+        args.iter().fold(self.tr(key).to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), &value.to_string())
+        })
+    }
+
+    fn load(language: Language) -> HashMap<String, String> {
+        let mut strings = parse(language.catalog());
+
+        let path = Path::new(OVERRIDE_DIR).join(format!("{}.lang", language.code()));
+        if let Ok(contents) = fs::read_to_string(&path) {
+            strings.extend(parse(&contents));
+        }
+
+        strings
+    }
+}
+
+fn parse(contents: &str) -> HashMap<String, String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+        .collect()
+}
@@ -0,0 +1,174 @@
+use rand::Rng;
+
+use crate::config::Endpoints;
+use crate::locale::Locale;
+
+// Діапазони, з яких обираються випадкові задачі; неіснуючі відсіюються під час завантаження назви
+const EOLYMP_IDS: (u32, u32) = (1, 12000);
+const CODEFORCES_CONTESTS: (u32, u32) = (1, 2000);
+const CODEFORCES_INDICES: &[&str] = &["A", "B", "C", "D", "E", "F"];
+const ATCODER_CONTESTS: (u32, u32) = (1, 350);
+const ATCODER_TASKS: &[&str] = &["a", "b", "c", "d", "e", "f"];
+const TIMUS_IDS: (u32, u32) = (1000, 2170);
+
+/// Онлайн-суддя, з якого беруться задачі.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Judge {
+    #[default]
+    Eolymp,
+    Codeforces,
+    AtCoder,
+    Timus,
+}
+
+impl Judge {
+    pub const ALL: [Judge; 4] = [Judge::Eolymp, Judge::Codeforces, Judge::AtCoder, Judge::Timus];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Judge::Eolymp => "eolymp",
+            Judge::Codeforces => "codeforces",
+            Judge::AtCoder => "atcoder",
+            Judge::Timus => "timus",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Judge::Eolymp => "Eolymp",
+            Judge::Codeforces => "Codeforces",
+            Judge::AtCoder => "AtCoder",
+            Judge::Timus => "Timus",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|judge| judge.code() == code)
+    }
+
+    pub fn random_id(self, rng: &mut impl Rng) -> String {
+        match self {
+            Judge::Eolymp => rng.random_range(EOLYMP_IDS.0..=EOLYMP_IDS.1).to_string(),
+            Judge::Codeforces => format!(
+                "{}{}",
+                rng.random_range(CODEFORCES_CONTESTS.0..=CODEFORCES_CONTESTS.1),
+                CODEFORCES_INDICES[rng.random_range(0..CODEFORCES_INDICES.len())]
+            ),
+            Judge::AtCoder => {
+                let contest = rng.random_range(ATCODER_CONTESTS.0..=ATCODER_CONTESTS.1);
+                format!("abc{:03}_{}", contest, ATCODER_TASKS[rng.random_range(0..ATCODER_TASKS.len())])
+            }
+            Judge::Timus => rng.random_range(TIMUS_IDS.0..=TIMUS_IDS.1).to_string(),
+        }
+    }
+
+    /// Перевіряє та нормалізує id у форматі судді: `1234`, `1234A`, `abc123_a`.
+    pub fn parse_id(self, text: &str) -> Option<String> {
+        let text = text.trim().trim_start_matches('#');
+
+        match self {
+            Judge::Eolymp | Judge::Timus => {
+                text.parse::<u32>().ok().filter(|&id| id > 0).map(|id| id.to_string())
+            }
+            Judge::Codeforces => {
+                let (contest, index) = split_codeforces_id(text)?;
+                Some(format!("{}{}", contest, index.to_uppercase()))
+            }
+            Judge::AtCoder => {
+                let (contest, task) = text.split_once('_')?;
+                let valid = !contest.is_empty()
+                    && contest.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+                    && !task.is_empty()
+                    && task.chars().all(|c| c.is_ascii_alphanumeric());
+                valid.then(|| text.to_lowercase())
+            }
+        }
+    }
+
+    pub fn problem_url(self, id: &str, locale: Locale, endpoints: &Endpoints) -> String {
+        match self {
+            Judge::Eolymp => endpoints.problem_url(id, locale),
+            Judge::Codeforces => {
+                let (contest, index) = split_codeforces_id(id).unwrap_or((id, ""));
+                format!("https://codeforces.com/problemset/problem/{}/{}?locale={}", contest, index, ru_or_en(locale))
+            }
+            Judge::AtCoder => {
+                let contest = id.split_once('_').map_or(id, |(contest, _)| contest);
+                format!("https://atcoder.jp/contests/{}/tasks/{}?lang=en", contest, id)
+            }
+            Judge::Timus => format!("https://acm.timus.ru/problem.aspx?num={}&locale={}", id, ru_or_en(locale)),
+        }
+    }
+}
+
+/// Випадкова задача одного з суддів; порядок звернень до `rng` важливий для відтворюваних послідовностей.
+pub fn random_problem(rng: &mut impl Rng, judges: &[Judge]) -> (Judge, String) {
+    let judge = judges.get(rng.random_range(0..judges.len().max(1))).copied().unwrap_or_default();
+    (judge, judge.random_id(rng))
+}
+
+// Codeforces та Timus мають лише російську та англійську версії умов
+fn ru_or_en(locale: Locale) -> &'static str {
+    match locale {
+        Locale::Ru => "ru",
+        _ => "en",
+    }
+}
+
+// "1234A" → ("1234", "A"); індекс може мати цифру, як у "1234B2"
+fn split_codeforces_id(id: &str) -> Option<(&str, &str)> {
+    let split = id.find(|c: char| !c.is_ascii_digit())?;
+    let (contest, index) = id.split_at(split);
+
+    let valid = !contest.is_empty()
+        && index.starts_with(|c: char| c.is_ascii_alphabetic())
+        && index.len() <= 2
+        && index.chars().all(|c| c.is_ascii_alphanumeric());
+    valid.then_some((contest, index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_validated_per_judge() {
+        assert_eq!(Judge::Eolymp.parse_id("#42"), Some("42".to_string()));
+        assert_eq!(Judge::Eolymp.parse_id("42A"), None);
+        assert_eq!(Judge::Codeforces.parse_id("1234a"), Some("1234A".to_string()));
+        assert_eq!(Judge::Codeforces.parse_id("1234B2"), Some("1234B2".to_string()));
+        assert_eq!(Judge::Codeforces.parse_id("1234"), None);
+        assert_eq!(Judge::AtCoder.parse_id("ABC123_A"), Some("abc123_a".to_string()));
+        assert_eq!(Judge::AtCoder.parse_id("abc123"), None);
+        assert_eq!(Judge::Timus.parse_id("1000"), Some("1000".to_string()));
+    }
+
+    #[test]
+    fn random_ids_are_valid() {
+        let mut rng = rand::rng();
+        for judge in Judge::ALL {
+            for _ in 0..50 {
+                let id = judge.random_id(&mut rng);
+                assert_eq!(judge.parse_id(&id), Some(id.clone()), "{:?} {}", judge, id);
+            }
+        }
+    }
+
+    #[test]
+    fn urls_follow_judge_layout() {
+        let endpoints = Endpoints::default();
+        assert_eq!(Judge::Eolymp.problem_url("7", Locale::Uk, &endpoints), "https://eolymp.com/uk/problems/7");
+        assert_eq!(
+            Judge::Codeforces.problem_url("1234A", Locale::Uk, &endpoints),
+            "https://codeforces.com/problemset/problem/1234/A?locale=en"
+        );
+        assert_eq!(
+            Judge::AtCoder.problem_url("abc123_a", Locale::En, &endpoints),
+            "https://atcoder.jp/contests/abc123/tasks/abc123_a?lang=en"
+        );
+        assert_eq!(
+            Judge::Timus.problem_url("1000", Locale::Ru, &endpoints),
+            "https://acm.timus.ru/problem.aspx?num=1000&locale=ru"
+        );
+    }
+}
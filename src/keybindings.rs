@@ -0,0 +1,269 @@
+use eframe::egui::{self, Key, KeyboardShortcut, Modifiers};
+
+/// Дія, яку можна викликати гарячою клавішею.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Command {
+    Generate,
+    Open,
+    Copy,
+    Save,
+    DeleteSelected,
+    FocusSearch,
+    ShowShortcuts,
+    Palette,
+}
+
+impl Command {
+    pub const ALL: [Command; 8] = [
+        Command::Generate,
+        Command::Open,
+        Command::Copy,
+        Command::Save,
+        Command::DeleteSelected,
+        Command::FocusSearch,
+        Command::ShowShortcuts,
+        Command::Palette,
+    ];
+
+    /// Ідентифікатор для збереження у налаштуваннях.
+    pub fn code(self) -> &'static str {
+        match self {
+            Command::Generate => "generate",
+            Command::Open => "open",
+            Command::Copy => "copy",
+            Command::Save => "save",
+            Command::DeleteSelected => "delete_selected",
+            Command::FocusSearch => "focus_search",
+            Command::ShowShortcuts => "show_shortcuts",
+            Command::Palette => "command_palette",
+        }
+    }
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Command::Generate => "command.generate",
+            Command::Open => "command.open",
+            Command::Copy => "command.copy",
+            Command::Save => "command.save",
+            Command::DeleteSelected => "command.delete_selected",
+            Command::FocusSearch => "command.focus_search",
+            Command::ShowShortcuts => "command.show_shortcuts",
+            Command::Palette => "command.command_palette",
+        }
+    }
+
+    pub fn default_shortcut(self) -> KeyboardShortcut {
+        match self {
+            Command::Generate => KeyboardShortcut::new(Modifiers::COMMAND, Key::G),
+            Command::Open => KeyboardShortcut::new(Modifiers::COMMAND, Key::O),
+            Command::Copy => KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::C),
+            Command::Save => KeyboardShortcut::new(Modifiers::COMMAND, Key::S),
+            Command::DeleteSelected => KeyboardShortcut::new(Modifiers::NONE, Key::Delete),
+            Command::FocusSearch => KeyboardShortcut::new(Modifiers::COMMAND, Key::F),
+            Command::ShowShortcuts => KeyboardShortcut::new(Modifiers::NONE, Key::F1),
+            Command::Palette => KeyboardShortcut::new(Modifiers::COMMAND, Key::K),
+        }
+    }
+}
+
+pub struct KeyBindings {
+    bindings: Vec<(Command, KeyboardShortcut)>,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            bindings: Command::ALL
+                .into_iter()
+                .map(|command| (command, command.default_shortcut()))
+                .collect(),
+        }
+    }
+}
+
+impl KeyBindings {
+    pub fn get(&self, command: Command) -> KeyboardShortcut {
+        self.bindings
+            .iter()
+            .find(|(c, _)| *c == command)
+            .map(|(_, shortcut)| *shortcut)
+            .unwrap_or_else(|| command.default_shortcut())
+    }
+
+    /// Прив'язки зі збережених налаштувань; нерозпізнані рядки лишають типову комбінацію.
+    pub fn load(mut setting: impl FnMut(Command) -> Option<String>) -> Self {
+        let mut bindings = Self::default();
+        for command in Command::ALL {
+            if let Some(shortcut) = setting(command).and_then(|text| parse_shortcut(&text)) {
+                bindings.set(command, shortcut);
+            }
+        }
+        bindings
+    }
+
+    /// Інша команда, якій уже призначено цю комбінацію.
+    pub fn conflict(&self, command: Command, shortcut: KeyboardShortcut) -> Option<Command> {
+        self.bindings
+            .iter()
+            .find(|(c, s)| *c != command && *s == shortcut)
+            .map(|(c, _)| *c)
+    }
+
+    /// Одна комбінація не може належати двом командам: та, що мала її раніше,
+    /// отримує попередню комбінацію `command`. Повертає цю команду, щоб зберегти і її.
+    pub fn set(&mut self, command: Command, shortcut: KeyboardShortcut) -> Option<Command> {
+        let previous = self.get(command);
+        let displaced = self.conflict(command, shortcut);
+
+        for (c, s) in self.bindings.iter_mut() {
+            if *c == command {
+                *s = shortcut;
+            }
+            else if Some(*c) == displaced {
+                *s = previous;
+            }
+        }
+        displaced
+    }
+
+    /// Повертає команди, гарячі клавіші яких натиснуто у цьому кадрі.
+    pub fn triggered(&self, ctx: &egui::Context) -> Vec<Command> {
+        // Поки фокус у текстовому полі, клавіші без модифікаторів належать йому
+        let typing = ctx.wants_keyboard_input();
+
+        // Спершу перевіряємо комбінації з більшою кількістю модифікаторів,
+        // щоб Ctrl+Shift+C не спрацював як Ctrl+C
+        let mut bindings = self.bindings.clone();
+        bindings.sort_by_key(|(_, shortcut)| std::cmp::Reverse(modifier_count(shortcut.modifiers)));
+
+        ctx.input_mut(|input| {
+            bindings
+                .into_iter()
+                .filter(|(_, shortcut)| !(typing && shortcut.modifiers.is_none()))
+                .filter(|(_, shortcut)| input.consume_shortcut(shortcut))
+                .map(|(command, _)| command)
+                .collect()
+        })
+    }
+}
+
+fn modifier_count(modifiers: Modifiers) -> usize {
+    [modifiers.command || modifiers.ctrl, modifiers.shift, modifiers.alt]
+        .into_iter()
+        .filter(|pressed| *pressed)
+        .count()
+}
+
+/// Текстове представлення комбінації, наприклад `Ctrl+Shift+C`.
+pub fn format_shortcut(shortcut: &KeyboardShortcut) -> String {
+    let mut parts = Vec::new();
+
+    if shortcut.modifiers.command || shortcut.modifiers.ctrl || shortcut.modifiers.mac_cmd {
+        parts.push("Ctrl");
+    }
+    if shortcut.modifiers.shift {
+        parts.push("Shift");
+    }
+    if shortcut.modifiers.alt {
+        parts.push("Alt");
+    }
+    parts.push(shortcut.logical_key.name());
+
+    parts.join("+")
+}
+
+pub fn parse_shortcut(text: &str) -> Option<KeyboardShortcut> {
+    let mut modifiers = Modifiers::NONE;
+    let mut parts = text.split('+').map(str::trim).collect::<Vec<_>>();
+    let key = Key::from_name(parts.pop()?)?;
+
+    for part in parts {
+        match part {
+            "Ctrl" | "Cmd" => modifiers |= Modifiers::COMMAND,
+            "Shift" => modifiers |= Modifiers::SHIFT,
+            "Alt" => modifiers |= Modifiers::ALT,
+            _ => return None,
+        }
+    }
+
+    Some(KeyboardShortcut::new(modifiers, key))
+}
+
+/// Ловить першу натиснуту клавішу — використовується при перепризначенні.
+pub fn capture_shortcut(ctx: &egui::Context) -> Option<KeyboardShortcut> {
+    ctx.input(|input| {
+        input.events.iter().find_map(|event| match event {
+            egui::Event::Key { key, pressed: true, modifiers, .. } => {
+                let mut normalized = Modifiers::NONE;
+                if modifiers.command || modifiers.ctrl || modifiers.mac_cmd {
+                    normalized |= Modifiers::COMMAND;
+                }
+                if modifiers.shift {
+                    normalized |= Modifiers::SHIFT;
+                }
+                if modifiers.alt {
+                    normalized |= Modifiers::ALT;
+                }
+                Some(KeyboardShortcut::new(normalized, *key))
+            }
+            _ => None,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortcuts_round_trip_through_text() {
+        for command in Command::ALL {
+            let shortcut = command.default_shortcut();
+            assert_eq!(parse_shortcut(&format_shortcut(&shortcut)), Some(shortcut));
+        }
+
+        assert_eq!(
+            parse_shortcut(" Ctrl + Shift + C "),
+            Some(KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::C))
+        );
+        assert_eq!(parse_shortcut("Cmd+K"), Some(KeyboardShortcut::new(Modifiers::COMMAND, Key::K)));
+    }
+
+    #[test]
+    fn malformed_shortcuts_are_rejected() {
+        assert_eq!(parse_shortcut(""), None);
+        assert_eq!(parse_shortcut("Ctrl+"), None);
+        assert_eq!(parse_shortcut("Hyper+G"), None);
+        assert_eq!(parse_shortcut("Ctrl+NoSuchKey"), None);
+    }
+
+    #[test]
+    fn conflicting_shortcut_swaps_with_previous_owner() {
+        let mut bindings = KeyBindings::default();
+        let generate = Command::Generate.default_shortcut();
+
+        assert_eq!(bindings.conflict(Command::Open, generate), Some(Command::Generate));
+        assert_eq!(bindings.conflict(Command::Generate, generate), None);
+
+        assert_eq!(bindings.set(Command::Open, generate), Some(Command::Generate));
+        assert_eq!(bindings.get(Command::Open), generate);
+        assert_eq!(bindings.get(Command::Generate), Command::Open.default_shortcut());
+
+        let free = KeyboardShortcut::new(Modifiers::ALT, Key::Q);
+        assert_eq!(bindings.set(Command::Copy, free), None);
+        assert_eq!(bindings.get(Command::Copy), free);
+    }
+
+    #[test]
+    fn persisted_bindings_skip_garbage() {
+        let bindings = KeyBindings::load(|command| match command {
+            Command::Generate => Some("Alt+N".to_string()),
+            Command::Save => Some("not a shortcut".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(bindings.get(Command::Generate), KeyboardShortcut::new(Modifiers::ALT, Key::N));
+        assert_eq!(bindings.get(Command::Save), Command::Save.default_shortcut());
+        assert_eq!(bindings.get(Command::Open), Command::Open.default_shortcut());
+    }
+}
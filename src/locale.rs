@@ -0,0 +1,41 @@
+/// Мова, якою eolymp показує умову задачі.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    Uk,
+    En,
+    Ru,
+    Az,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 4] = [
+        Locale::Uk,
+        Locale::En,
+        Locale::Ru,
+        Locale::Az,
+    ];
+
+    /// Код локалі, який eolymp використовує у шляху URL.
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::Uk => "uk",
+            Locale::En => "en",
+            Locale::Ru => "ru",
+            Locale::Az => "az",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Locale::Uk => "Українська",
+            Locale::En => "English",
+            Locale::Ru => "Русский",
+            Locale::Az => "Azərbaycan",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|locale| locale.code() == code)
+    }
+}
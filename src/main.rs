@@ -1,18 +1,86 @@
+mod api;
+mod cache;
+mod config;
+mod contest;
+mod daily;
 mod db;
+mod fetcher;
+mod http;
+mod i18n;
+mod judge;
+mod keybindings;
+mod locale;
+mod notifications;
+mod palette;
+mod prefetch;
+mod rating;
+mod review;
+mod seed;
+mod stats;
+mod theme;
+mod title;
+mod tracking;
+mod transport;
+mod weights;
 
-use std::sync::mpsc;
+use std::collections::HashMap;
 use std::time::{Duration, Instant};
-use std::thread;
 
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
 use eframe::egui;
-use rand::Rng;
-use scraper::{Html, Selector};
 
+use crate::config::Endpoints;
+use crate::contest::{Draft, Preparation, Source};
 use crate::db::Database;
+use crate::fetcher::{CancelToken, FetchError, Fetcher, RequestId, TitleResponse, TitleSource};
+use crate::http::HttpConfig;
+use crate::i18n::{Language, Translations};
+use crate::judge::Judge;
+use crate::keybindings::{Command, KeyBindings};
+use crate::locale::Locale;
+use crate::notifications::{Notifications, RetryAction, Severity};
+use crate::palette::{Palette, PaletteAction, PaletteItem};
+use crate::prefetch::PrefetchPool;
+use crate::rating::Outcome;
+use crate::review::Grade;
+use crate::seed::{SeededRun, ShareCode};
+use crate::stats::Dashboard;
+use crate::theme::{Theme, ThemeChoice, Themes};
+use crate::title::ProblemMeta;
+use crate::tracking::Tracker;
+use crate::transport::TransportMode;
+use crate::weights::{Choice, Weights};
 
-const BASE_URL: &str = "https://eolymp.com/uk/problems";
-const MIN_PROBLEM_ID: u32 = 1;
-const MAX_PROBLEM_ID: u32 = 12000;
+const LOCALE_SETTING: &str = "locale";
+const LANGUAGE_SETTING: &str = "language";
+const THEME_SETTING: &str = "theme";
+const KEYBINDING_SETTING_PREFIX: &str = "keybinding.";
+const HTTP_TIMEOUT_SETTING: &str = "http.timeout_secs";
+const HTTP_RETRIES_SETTING: &str = "http.max_retries";
+const HTTP_USER_AGENT_SETTING: &str = "http.user_agent";
+const HTTP_PROXY_SETTING: &str = "http.proxy";
+const HTTP_CACHE_TTL_SETTING: &str = "http.cache_ttl_hours";
+const HTTP_CACHE_SIZE_SETTING: &str = "http.cache_max_mb";
+const PREFETCH_SIZE_SETTING: &str = "prefetch.size";
+const TITLE_SOURCE_SETTING: &str = "title_source";
+const JUDGES_SETTING: &str = "judges";
+const DAILY_MODE_SETTING: &str = "daily.enabled";
+const DAILY_SALT_SETTING: &str = "daily.salt";
+const WEIGHTS_ENABLED_SETTING: &str = "weights.enabled";
+const WEIGHTS_FRESH_SETTING: &str = "weights.fresh";
+const WEIGHTS_TAGS_SETTING: &str = "weights.tags";
+const WEIGHTS_SKIPPED_SETTING: &str = "weights.skipped";
+const WEIGHTS_DIFFICULTY_SETTING: &str = "weights.difficulty";
+const WEIGHTS_TARGET_SETTING: &str = "weights.target";
+const LADDER_SETTING: &str = "rating.ladder";
+const SEARCH_ID: &str = "saved_search";
+const WEIGHTS_PREVIEW_ROWS: usize = 8;
+const SPARKLINE_POINTS: usize = 30;
+const UNDO_TIMEOUT: Duration = Duration::from_secs(5);
+const PROBE_INTERVAL: Duration = Duration::from_secs(15);
+// Черга назв, на яку сервер відповів 429 чи 5xx, повторюється з дедалі довшою паузою
+const REPLAY_MIN_BACKOFF: Duration = Duration::from_secs(5);
+const REPLAY_MAX_BACKOFF: Duration = Duration::from_secs(300);
 
 fn main() -> Result<(), eframe::Error> {
     let options = eframe::NativeOptions {
@@ -24,7 +92,7 @@ fn main() -> Result<(), eframe::Error> {
     eframe::run_native(
         "Eolymp Problem Generator",
         options,
-        Box::new(|_cc| Ok(Box::new(MyApp::new())))
+        Box::new(|cc| Ok(Box::new(MyApp::new(cc))))
     )
 }
 
@@ -35,201 +103,2983 @@ enum AppAction {
     Copied,
     Saved,
     Deleted,
+    Restored,
+    Reported,
+    Reviewed,
 }
 
 struct MyApp {
     url: String,
-    problem_id: Option<u32>,
+    judge: Judge,
+    problem_id: Option<String>,
     name: Option<String>,
     is_loading: bool,
+    title_error: Option<FetchError>,
+    next_request_id: RequestId,
+    pending_fetch: Option<(RequestId, CancelToken)>,
+    locale: Locale,
+    judges: Vec<Judge>,
+    i18n: Translations,
+    themes: Themes,
+    theme_choice: ThemeChoice,
+    theme: Theme,
+    theme_applied: bool,
+    show_settings: bool,
+    keybindings: KeyBindings,
+    capturing: Option<Command>,
+    show_shortcuts: bool,
+    search: String,
+    focus_search: bool,
+    selected_problem: Option<i32>,
+    palette: Option<Palette>,
     last_action: Option<AppAction>,
     timestamp: Option<Instant>,
     saved_problems: Vec<db::Problem>,
+    deleted_problems: Vec<db::Problem>,
+    last_deleted: Option<i32>,
+    show_trash: bool,
+    confirm_empty_trash: bool,
+    reviews: HashMap<i32, db::Review>,
+    show_reviews: bool,
+    show_contest: bool,
+    contests: Vec<db::Contest>,
+    contest_problems: HashMap<i32, Vec<db::ContestProblem>>,
+    contest_draft: Draft,
+    contest_preparation: Option<Preparation>,
+    contest_fetches: Vec<CancelToken>,
+    tracker: Option<Tracker>,
+    problem_time: HashMap<(String, String), i64>,
+    work_sessions: Vec<(NaiveDateTime, NaiveDateTime)>,
+    show_stats: bool,
+    stats: Dashboard,
+    notifications: Notifications,
+    error_log: Vec<db::LogEntry>,
+    show_error_log: bool,
     db: Database,
-    rx: mpsc::Receiver<String>,
-    tx: mpsc::Sender<String>,
+    fetcher: Fetcher,
+    endpoints: Endpoints,
+    prefetch: PrefetchPool,
+    http_config: HttpConfig,
+    http_draft: HttpConfig,
+    cache_size: Option<u64>,
+    offline: bool,
+    probe: Option<(RequestId, CancelToken)>,
+    next_probe: Option<Instant>,
+    pending_fetches: Vec<db::PendingFetch>,
+    replaying: HashMap<RequestId, i32>,
+    next_replay: Option<Instant>,
+    replay_backoff: Duration,
+    daily_mode: bool,
+    daily_salt: String,
+    daily: Option<(NaiveDate, u32)>,
+    daily_problems: Vec<db::DailyProblem>,
+    show_calendar: bool,
+    calendar_month: NaiveDate,
+    seeded: Option<SeededRun>,
+    seed_input: String,
+    weights: Weights,
+    history: Vec<db::HistoryEntry>,
+    ladder: bool,
+    rating_history: Vec<db::RatingChange>,
+    reported: Option<(Judge, String)>,
+    report_minutes: u32,
 }
 
 impl MyApp {
-    fn new() -> Self {
-        let (tx, rx) = mpsc::channel();
-        let db = Database::new().expect("Could not initialize database");
+    fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        let (endpoints, config_errors) = Endpoints::load();
+        let mut fetcher = Fetcher::new(cc.egui_ctx.clone()).expect("Could not start network executor");
+        fetcher.set_api_url(endpoints.api_url.clone());
+        let mut db = Database::new().expect("Could not initialize database");
+        if let Some(source) = db.get_setting(TITLE_SOURCE_SETTING)
+            .ok()
+            .flatten()
+            .and_then(|code| TitleSource::from_code(&code))
+        {
+            fetcher.set_source(source);
+        }
+        let locale = db.get_setting(LOCALE_SETTING)
+            .ok()
+            .flatten()
+            .and_then(|code| Locale::from_code(&code))
+            .unwrap_or_default();
+        let language = db.get_setting(LANGUAGE_SETTING)
+            .ok()
+            .flatten()
+            .and_then(|code| Language::from_code(&code))
+            .unwrap_or_default();
+        let theme_choice = db.get_setting(THEME_SETTING)
+            .ok()
+            .flatten()
+            .and_then(|code| ThemeChoice::from_code(&code))
+            .unwrap_or_default();
+
+        let keybindings = KeyBindings::load(|command| {
+            db.get_setting(&format!("{}{}", KEYBINDING_SETTING_PREFIX, command.code()))
+                .ok()
+                .flatten()
+        });
+
+        let judges = db.get_setting(JUDGES_SETTING)
+            .ok()
+            .flatten()
+            .map(|codes| codes.split(',').filter_map(Judge::from_code).collect::<Vec<_>>())
+            .filter(|judges| !judges.is_empty())
+            .unwrap_or_else(|| vec![Judge::default()]);
+
+        let prefetch_size = db.get_setting(PREFETCH_SIZE_SETTING)
+            .ok()
+            .flatten()
+            .and_then(|size| size.parse().ok())
+            .unwrap_or(prefetch::DEFAULT_POOL_SIZE);
+
+        let daily_mode = db.get_setting(DAILY_MODE_SETTING).ok().flatten().as_deref() == Some("true");
+        let daily_salt = db.get_setting(DAILY_SALT_SETTING).ok().flatten().unwrap_or_default();
+
+        let ladder = db.get_setting(LADDER_SETTING).ok().flatten().as_deref() == Some("true");
+
+        let (themes, theme_errors) = Themes::load();
 
         let mut app = Self {
             url: String::new(),
+            judge: Judge::default(),
             problem_id: None,
             name: None,
             is_loading: false,
+            title_error: None,
+            next_request_id: 0,
+            pending_fetch: None,
+            locale,
+            judges: judges.clone(),
+            i18n: Translations::new(language),
+            themes,
+            theme_choice,
+            theme: Theme::dark(),
+            theme_applied: false,
+            show_settings: false,
+            keybindings,
+            capturing: None,
+            show_shortcuts: false,
+            search: String::new(),
+            focus_search: false,
+            selected_problem: None,
+            palette: None,
             last_action: None,
             timestamp: None,
             saved_problems: Vec::new(),
+            deleted_problems: Vec::new(),
+            last_deleted: None,
+            show_trash: false,
+            confirm_empty_trash: false,
+            reviews: HashMap::new(),
+            show_reviews: false,
+            show_contest: false,
+            contests: Vec::new(),
+            contest_problems: HashMap::new(),
+            contest_draft: Draft::new(judges.clone()),
+            contest_preparation: None,
+            contest_fetches: Vec::new(),
+            tracker: None,
+            problem_time: HashMap::new(),
+            work_sessions: Vec::new(),
+            show_stats: false,
+            stats: Dashboard::default(),
+            notifications: Notifications::default(),
+            error_log: Vec::new(),
+            show_error_log: false,
             db,
-            rx,
-            tx,
+            fetcher,
+            endpoints,
+            prefetch: PrefetchPool::new(prefetch_size, locale, judges),
+            http_config: HttpConfig::default(),
+            http_draft: HttpConfig::default(),
+            cache_size: None,
+            offline: false,
+            probe: None,
+            next_probe: None,
+            pending_fetches: Vec::new(),
+            replaying: HashMap::new(),
+            next_replay: None,
+            replay_backoff: REPLAY_MIN_BACKOFF,
+            daily_mode,
+            daily_salt,
+            daily: None,
+            daily_problems: Vec::new(),
+            show_calendar: false,
+            calendar_month: daily::today(),
+            seeded: None,
+            seed_input: String::new(),
+            weights: Weights::default(),
+            history: Vec::new(),
+            ladder,
+            rating_history: Vec::new(),
+            reported: None,
+            report_minutes: 0,
         };
 
         app.reload_problems();
+        app.reload_error_log();
+        app.reload_pending_fetches();
+        app.reload_daily_problems();
+        app.reload_history();
+        app.reload_rating_history();
+        app.reload_reviews();
+        app.reload_contests();
+        app.restore_tracker();
+        app.reload_time_totals();
+        app.weights = app.load_weights();
+        let http_config = app.load_http_config();
+        app.apply_http_config(http_config);
+        app.replay_pending_fetches();
+        for error in config_errors {
+            app.report(Severity::Warning, "error.config", error, None);
+        }
+        for error in theme_errors {
+            app.report(Severity::Warning, "error.theme_load", error, None);
+        }
         app
     }
 
-    fn generate_url(&mut self) {
-        let mut rng = rand::rng();
-        let problem_id = rng.random_range(MIN_PROBLEM_ID..=MAX_PROBLEM_ID);
+    fn generate_url(&mut self) {
+        // Явно запущена послідовність за зерном важливіша за задачу дня
+        if let Some(run) = &mut self.seeded {
+            let (judge, problem_id) = run.next_problem();
+            self.daily = None;
+            self.show_problem(judge, problem_id);
+            return;
+        }
+
+        if self.daily_mode {
+            self.open_daily(daily::today());
+            return;
+        }
+        self.daily = None;
+
+        let weights = self.effective_weights();
+        if weights.enabled {
+            let distribution = weights::distribution(&weights, &self.history, chrono::Utc::now().naive_utc());
+            // Поточну задачу щойно показано, тож вона не повертається одразу ж
+            if let Choice::Known(idx) = distribution.pick(&mut rand::rng())
+                && let entry = &self.history[idx]
+                && !(entry.judge == self.judge.code() && self.problem_id.as_deref() == Some(entry.problem_id.as_str()))
+            {
+                let judge = Judge::from_code(&entry.judge).unwrap_or_default();
+                let problem_id = entry.problem_id.clone();
+                self.show_problem(judge, problem_id);
+                return;
+            }
+        }
+
+        // Готова задача з пулу показується миттєво, без очікування мережі
+        let prefetched = if self.ladder {
+            self.prefetch.pop_nearest(weights.target)
+        }
+        else {
+            self.prefetch.pop()
+        };
+        if let Some(problem) = prefetched {
+            self.cancel_fetch();
+            self.judge = problem.judge;
+            self.problem_id = Some(problem.problem_id);
+            self.url = problem.url;
+            self.record_shown(&problem.title, &problem.meta);
+            self.name = Some(problem.title);
+            self.title_error = None;
+            self.set_action(AppAction::Generated);
+            return;
+        }
+
+        let (judge, problem_id) = judge::random_problem(&mut rand::rng(), &self.judges);
+        self.show_problem(judge, problem_id);
+    }
+
+    fn show_problem(&mut self, judge: Judge, problem_id: String) {
+        self.judge = judge;
+        self.url = self.build_url(judge, &problem_id, self.locale);
+        self.problem_id = Some(problem_id.clone());
+        self.set_action(AppAction::Generated);
+
+        if !self.offline {
+            self.fetch_title();
+            return;
+        }
+
+        // Без мережі назву можна взяти хіба що зі збережених задач,
+        // решта завантажиться після відновлення зв'язку
+        self.cancel_fetch();
+        self.title_error = None;
+        self.name = self.saved_problems
+            .iter()
+            .find(|problem| {
+                problem.judge == judge.code()
+                    && problem.problem_id == problem_id
+                    && !problem.name.is_empty()
+                    && problem.locale.as_deref() == Some(self.locale.code())
+            })
+            .map(|problem| problem.name.clone());
+    }
+
+    /// Запускає відтворювану послідовність задач; порожній рядок означає нове випадкове зерно.
+    fn start_seeded_run(&mut self, text: &str) {
+        let code = if text.trim().is_empty() {
+            ShareCode::random(&self.judges)
+        }
+        else {
+            match ShareCode::parse(text, &self.judges) {
+                Some(code) => code,
+                None => {
+                    self.report(Severity::Warning, "error.seed", text.trim(), None);
+                    return;
+                }
+            }
+        };
+
+        self.seed_input = code.encode();
+        self.seeded = Some(SeededRun::new(code));
+        self.generate_url();
+    }
+
+    fn stop_seeded_run(&mut self) {
+        self.seeded = None;
+        self.seed_input.clear();
+    }
+
+    fn record_shown(&mut self, name: &str, meta: &ProblemMeta) {
+        let Some(problem_id) = self.problem_id.clone() else {
+            return;
+        };
+
+        let tags = meta.tags.join(",");
+        let difficulty = meta.difficulty.map(|difficulty| difficulty as i32);
+        if let Err(e) = self.db.record_shown(self.judge.code(), &problem_id, name, &tags, difficulty) {
+            self.report(Severity::Warning, "error.history", e, None);
+            return;
+        }
+        self.reload_history();
+    }
+
+    fn record_opened(&mut self) {
+        let Some(problem_id) = self.problem_id.clone() else {
+            return;
+        };
+
+        if let Err(e) = self.db.record_opened(self.judge.code(), &problem_id) {
+            self.report(Severity::Warning, "error.history", e, None);
+            return;
+        }
+        self.reload_history();
+    }
+
+    fn reload_history(&mut self) {
+        match self.db.get_history() {
+            Ok(history) => self.history = history,
+            Err(e) => self.report(Severity::Warning, "error.history", e, None),
+        }
+    }
+
+    /// У режимі рейтингової драбини цільова складність іде за поточним рейтингом.
+    fn effective_weights(&self) -> Weights {
+        let mut weights = self.weights.clone();
+        if self.ladder {
+            weights.enabled = true;
+            weights.target = rating::target(self.rating());
+        }
+        weights
+    }
+
+    fn rating(&self) -> f64 {
+        self.rating_history.last().map_or(rating::INITIAL_RATING, |change| change.rating_after)
+    }
+
+    fn report_result(&mut self, solved: bool) {
+        let Some(problem_id) = self.problem_id.clone() else {
+            return;
+        };
+
+        // Задачу без відомої складності вважаємо рівною поточному рейтингу
+        let rating = self.rating();
+        let difficulty = self.history
+            .iter()
+            .find(|entry| entry.judge == self.judge.code() && entry.problem_id == problem_id)
+            .and_then(|entry| entry.difficulty)
+            .map_or(rating, f64::from);
+
+        // Без явно вказаних хвилин береться час із таймера задачі
+        let tracked = (self.time_spent(self.judge, &problem_id) / 60) as u32;
+        let minutes = [self.report_minutes, tracked].into_iter().find(|&minutes| minutes > 0);
+        let (rating_after, difficulty_after) = rating::update(rating, difficulty, Outcome { solved, minutes });
+
+        let change = db::RatingChange {
+            id: 0,
+            judge: self.judge.code().to_string(),
+            problem_id: problem_id.clone(),
+            solved,
+            minutes: minutes.map(|minutes| minutes as i32),
+            rating_before: rating,
+            rating_after,
+            difficulty_before: difficulty,
+            difficulty_after,
+            created_at: chrono::Utc::now().naive_utc(),
+        };
+        if let Err(e) = self.db.record_rating_change(&change) {
+            self.report(Severity::Error, "error.rating", e, None);
+            return;
+        }
+
+        self.reported = Some((self.judge, problem_id));
+        self.report_minutes = 0;
+        self.set_action(AppAction::Reported);
+        self.reload_rating_history();
+        self.reload_history();
+        if self.show_stats {
+            self.reload_stats();
+        }
+    }
+
+    fn reload_rating_history(&mut self) {
+        match self.db.get_rating_history() {
+            Ok(history) => self.rating_history = history,
+            Err(e) => self.report(Severity::Warning, "error.rating", e, None),
+        }
+    }
+
+    fn set_ladder(&mut self, enabled: bool) {
+        self.ladder = enabled;
+
+        if let Err(e) = self.db.set_setting(LADDER_SETTING, if enabled { "true" } else { "false" }) {
+            self.report(Severity::Warning, "error.settings_save", e, None);
+        }
+    }
+
+    fn load_weights(&mut self) -> Weights {
+        let mut weights = Weights::default();
+        let mut setting = |key: &str| self.db.get_setting(key).ok().flatten();
+
+        weights.enabled = setting(WEIGHTS_ENABLED_SETTING).as_deref() == Some("true");
+        for (key, value) in [
+            (WEIGHTS_FRESH_SETTING, &mut weights.fresh),
+            (WEIGHTS_TAGS_SETTING, &mut weights.tags),
+            (WEIGHTS_SKIPPED_SETTING, &mut weights.skipped),
+            (WEIGHTS_DIFFICULTY_SETTING, &mut weights.difficulty),
+        ] {
+            if let Some(weight) = setting(key).and_then(|v| v.parse::<f32>().ok()) {
+                *value = weight.clamp(0.0, weights::MAX_WEIGHT);
+            }
+        }
+        if let Some(target) = setting(WEIGHTS_TARGET_SETTING).and_then(|v| v.parse().ok()) {
+            weights.target = target;
+        }
+
+        weights
+    }
+
+    fn set_weights(&mut self, weights: Weights) {
+        let values = [
+            (WEIGHTS_ENABLED_SETTING, weights.enabled.to_string()),
+            (WEIGHTS_FRESH_SETTING, weights.fresh.to_string()),
+            (WEIGHTS_TAGS_SETTING, weights.tags.to_string()),
+            (WEIGHTS_SKIPPED_SETTING, weights.skipped.to_string()),
+            (WEIGHTS_DIFFICULTY_SETTING, weights.difficulty.to_string()),
+            (WEIGHTS_TARGET_SETTING, weights.target.to_string()),
+        ];
+        for (key, value) in values {
+            if let Err(e) = self.db.set_setting(key, &value) {
+                self.report(Severity::Warning, "error.settings_save", e, None);
+                break;
+            }
+        }
+
+        self.weights = weights;
+    }
+
+    fn build_url(&self, judge: Judge, id: &str, locale: Locale) -> String {
+        judge.problem_url(id, locale, &self.endpoints)
+    }
+
+    /// Відкриває задачу дня; якщо її вже бачили, береться збережений результат.
+    fn open_daily(&mut self, date: NaiveDate) {
+        let known = self.daily_problems.iter().find(|daily| daily.date == date).cloned();
+
+        match &known {
+            Some(known) => {
+                self.judge = Judge::from_code(&known.judge).unwrap_or_default();
+                self.problem_id = Some(known.problem_id.clone());
+                self.url = self.build_url(self.judge, &known.problem_id, self.locale);
+                self.daily = Some((date, known.attempt as u32));
+                self.set_action(AppAction::Generated);
+            }
+            None => self.show_daily_candidate(date, 0),
+        }
+
+        if !self.offline {
+            self.fetch_title();
+            return;
+        }
+
+        self.cancel_fetch();
+        self.title_error = None;
+        self.name = known.map(|known| known.name).filter(|name| !name.is_empty());
+    }
+
+    fn show_daily_candidate(&mut self, date: NaiveDate, attempt: u32) {
+        let (judge, problem_id) = daily::pick(date, &self.daily_salt, &self.judges, attempt);
+
+        self.judge = judge;
+        self.url = self.build_url(judge, &problem_id, self.locale);
+        self.problem_id = Some(problem_id);
+        self.daily = Some((date, attempt));
+        self.set_action(AppAction::Generated);
+    }
+
+    fn record_daily(&mut self, date: NaiveDate, attempt: u32, name: String) {
+        let Some(problem_id) = self.problem_id.clone() else {
+            return;
+        };
+
+        let daily = db::DailyProblem {
+            date,
+            salt: self.daily_salt.trim().to_string(),
+            judge: self.judge.code().to_string(),
+            problem_id,
+            name,
+            attempt: attempt as i32,
+        };
+        if let Err(e) = self.db.save_daily_problem(&daily) {
+            self.report(Severity::Error, "error.save", e, None);
+            return;
+        }
+        self.reload_daily_problems();
+    }
+
+    fn reload_daily_problems(&mut self) {
+        match self.db.get_daily_problems(self.daily_salt.trim()) {
+            Ok(problems) => self.daily_problems = problems,
+            Err(e) => self.report(Severity::Error, "error.reload", e, None),
+        }
+    }
+
+    fn set_daily_mode(&mut self, enabled: bool) {
+        self.daily_mode = enabled;
+
+        if let Err(e) = self.db.set_setting(DAILY_MODE_SETTING, if enabled { "true" } else { "false" }) {
+            self.report(Severity::Warning, "error.settings_save", e, None);
+        }
+    }
+
+    fn set_daily_salt(&mut self, salt: String) {
+        if let Err(e) = self.db.set_setting(DAILY_SALT_SETTING, &salt) {
+            self.report(Severity::Warning, "error.settings_save", e, None);
+        }
+
+        self.daily_salt = salt;
+        self.daily = None;
+        self.reload_daily_problems();
+    }
+
+    fn set_judges(&mut self, judges: Vec<Judge>) {
+        let codes = judges.iter().map(|judge| judge.code()).collect::<Vec<_>>().join(",");
+        if let Err(e) = self.db.set_setting(JUDGES_SETTING, &codes) {
+            self.report(Severity::Warning, "error.settings_save", e, None);
+        }
+
+        self.prefetch.set_judges(judges.clone());
+        self.judges = judges;
+    }
+
+    fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+        self.prefetch.set_locale(locale);
+
+        if let Err(e) = self.db.set_setting(LOCALE_SETTING, locale.code()) {
+            self.report(Severity::Warning, "error.settings_save", e, None);
+        }
+
+        // Назва задачі залежить від локалі, тому її треба завантажити заново
+        if let Some(id) = self.problem_id.clone() {
+            self.url = self.build_url(self.judge, &id, locale);
+            self.fetch_title();
+        }
+    }
+
+    fn set_language(&mut self, language: Language) {
+        self.i18n = Translations::new(language);
+
+        if let Err(e) = self.db.set_setting(LANGUAGE_SETTING, language.code()) {
+            self.report(Severity::Warning, "error.settings_save", e, None);
+        }
+    }
+
+    fn set_theme_choice(&mut self, choice: ThemeChoice) {
+        if let Err(e) = self.db.set_setting(THEME_SETTING, &choice.code()) {
+            self.report(Severity::Warning, "error.settings_save", e, None);
+        }
+        self.theme_choice = choice;
+    }
+
+    // Тема "як у системі" може змінитися будь-коли, тому перевіряємо її щокадру
+    fn update_theme(&mut self, ctx: &egui::Context) {
+        let theme = self.themes.resolve(&self.theme_choice, ctx.system_theme());
+
+        if !self.theme_applied || theme != self.theme {
+            theme.apply(ctx);
+            self.theme = theme;
+            self.theme_applied = true;
+        }
+    }
+
+    fn set_keybinding(&mut self, command: Command, shortcut: egui::KeyboardShortcut) {
+        let displaced = self.keybindings.set(command, shortcut);
+
+        for command in std::iter::once(command).chain(displaced) {
+            let key = format!("{}{}", KEYBINDING_SETTING_PREFIX, command.code());
+            if let Err(e) = self.db.set_setting(&key, &keybindings::format_shortcut(&self.keybindings.get(command))) {
+                self.report(Severity::Warning, "error.settings_save", e, None);
+                break;
+            }
+        }
+    }
+
+    fn reset_keybindings(&mut self) {
+        for command in Command::ALL {
+            self.set_keybinding(command, command.default_shortcut());
+        }
+    }
+
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        // Під час перепризначення наступна натиснута клавіша стає новою комбінацією
+        if let Some(command) = self.capturing {
+            if let Some(shortcut) = keybindings::capture_shortcut(ctx) {
+                if shortcut.logical_key != egui::Key::Escape || !shortcut.modifiers.is_none() {
+                    self.set_keybinding(command, shortcut);
+                }
+                self.capturing = None;
+            }
+            return;
+        }
+
+        for command in self.keybindings.triggered(ctx) {
+            self.run_command(ctx, command);
+        }
+    }
+
+    fn run_command(&mut self, ctx: &egui::Context, command: Command) {
+        match command {
+            Command::Generate => self.generate_url(),
+            Command::Open => {
+                if self.is_url_valid() {
+                    self.open_url(self.url.clone());
+                }
+            }
+            Command::Copy => {
+                if self.is_url_valid() {
+                    self.copy(ctx, self.url.clone());
+                }
+            }
+            Command::Save => self.save(),
+            Command::DeleteSelected => {
+                if let Some(id) = self.selected_problem.take() {
+                    self.delete_saved_problem(id);
+                }
+            }
+            Command::FocusSearch => self.focus_search = true,
+            Command::ShowShortcuts => self.show_shortcuts = !self.show_shortcuts,
+            Command::Palette => {
+                self.palette = match self.palette {
+                    Some(_) => None,
+                    None => Some(Palette::default()),
+                };
+            }
+        }
+    }
+
+    fn palette_items(&self, query: &str) -> Vec<PaletteItem> {
+        let mut items = Vec::new();
+
+        // Запит у форматі id судді відкриває задачу напряму, навіть якщо її не збережено
+        for judge in self.judges.iter().copied() {
+            if let Some(id) = judge.parse_id(query) {
+                items.push(PaletteItem {
+                    label: format!("{} #{}", judge.label(), id),
+                    hint: self.i18n.tr("palette.open_problem").to_string(),
+                    action: PaletteAction::OpenProblem(self.build_url(judge, &id, self.locale)),
+                });
+            }
+        }
+
+        for command in Command::ALL.into_iter().filter(|c| *c != Command::Palette) {
+            items.push(PaletteItem {
+                label: self.i18n.tr(command.label_key()).to_string(),
+                hint: keybindings::format_shortcut(&self.keybindings.get(command)),
+                action: PaletteAction::Command(command),
+            });
+        }
+
+        items.push(PaletteItem {
+            label: self.i18n.tr("palette.settings").to_string(),
+            hint: String::new(),
+            action: PaletteAction::OpenSettings,
+        });
+
+        let themes = ThemeChoice::BUILTIN
+            .into_iter()
+            .chain(self.themes.custom().iter().map(|theme| ThemeChoice::Custom(theme.name.clone())));
+        for choice in themes {
+            items.push(PaletteItem {
+                label: self.i18n.tr_args("palette.theme", &[("name", &self.theme_label(&choice))]),
+                hint: String::new(),
+                action: PaletteAction::SetTheme(choice),
+            });
+        }
+
+        for language in Language::ALL {
+            items.push(PaletteItem {
+                label: self.i18n.tr_args("palette.language", &[("name", &language.label())]),
+                hint: String::new(),
+                action: PaletteAction::SetLanguage(language),
+            });
+        }
+
+        for locale in Locale::ALL {
+            items.push(PaletteItem {
+                label: self.i18n.tr_args("palette.locale", &[("name", &locale.label())]),
+                hint: String::new(),
+                action: PaletteAction::SetLocale(locale),
+            });
+        }
+
+        for problem in &self.saved_problems {
+            items.push(PaletteItem {
+                label: format!("{} #{} {}", problem_judge(problem).label(), problem.problem_id, problem.name),
+                hint: self.i18n.tr("palette.open_problem").to_string(),
+                action: PaletteAction::OpenProblem(problem.url.clone()),
+            });
+        }
+
+        items
+    }
+
+    fn run_palette_action(&mut self, ctx: &egui::Context, action: PaletteAction) {
+        match action {
+            PaletteAction::Command(command) => self.run_command(ctx, command),
+            PaletteAction::OpenSettings => self.show_settings = true,
+            PaletteAction::SetTheme(choice) => self.set_theme_choice(choice),
+            PaletteAction::SetLanguage(language) => self.set_language(language),
+            PaletteAction::SetLocale(locale) => self.set_locale(locale),
+            PaletteAction::OpenProblem(url) => self.open_url(url),
+        }
+    }
+
+    fn matches_search(&self, problem: &db::Problem) -> bool {
+        let query = self.search.trim().to_lowercase();
+
+        query.is_empty()
+            || problem.name.to_lowercase().contains(&query)
+            || problem.problem_id.to_lowercase().contains(query.trim_start_matches('#'))
+            || problem_judge(problem).label().to_lowercase().contains(&query)
+    }
+
+    fn set_problem_locale(&mut self, id: i32, locale: Locale) {
+        let Some(problem) = self.saved_problems.iter().find(|problem| problem.id == id).cloned() else {
+            return;
+        };
+
+        if problem.locale.as_deref() == Some(locale.code()) {
+            return;
+        }
+
+        let url = self.build_url(problem_judge(&problem), &problem.problem_id, locale);
+        if let Err(e) = self.db.set_problem_locale(id, locale.code(), url.clone()) {
+            self.report(Severity::Error, "error.problem_locale", e, None);
+            return;
+        }
+
+        // Назва мовою задачі завантажується через чергу, тож без мережі дочекається зв'язку
+        self.enqueue_fetch(&problem, &url, locale);
+        if !self.offline {
+            self.replay_pending_fetches();
+        }
+        self.reload_problems();
+    }
+
+    fn open_url(&mut self, url: String) {
+        if let Err(e) = open::that(&url) {
+            self.report(Severity::Error, "error.open_url", e, Some(RetryAction::OpenUrl(url)));
+            return;
+        }
+        if url == self.url {
+            self.record_opened();
+        }
+        self.set_action(AppAction::Opened);
+    }
+
+    fn is_url_valid(&self) -> bool {
+        self.problem_id.is_some()
+    }
+
+    fn copy(&mut self, ctx: &egui::Context, url: String) {
+        ctx.copy_text(url);
+        self.set_action(AppAction::Copied);
+    }
+
+    // Задача без назви теж зберігається, а назва підтягнеться з черги пізніше
+    fn save(&mut self) {
+        let Some(problem_id) = self.problem_id.clone() else {
+            return;
+        };
+        let name = self.name.clone().unwrap_or_default();
+
+        let id = match self.db.save_problem(self.judge.code(), &problem_id, name.clone(), self.url.clone(), self.locale.code()) {
+            Ok(id) => {
+                self.set_action(AppAction::Saved);
+                self.reload_problems();
+                self.record_opened();
+                self.schedule_review(id);
+                id
+            }
+            Err(e) => {
+                self.report(Severity::Error, "error.save", e, Some(RetryAction::Save));
+                return;
+            }
+        };
+
+        if name.is_empty()
+            && let Some(problem) = self.saved_problems.iter().find(|problem| problem.id == id).cloned()
+        {
+            let url = self.url.clone();
+            self.enqueue_fetch(&problem, &url, self.locale);
+            if !self.offline {
+                self.replay_pending_fetches();
+            }
+        }
+    }
+
+    fn schedule_review(&mut self, id: i32) {
+        if let Err(e) = self.db.schedule_review(&review::initial(id, daily::today())) {
+            self.report(Severity::Warning, "error.review", e, None);
+        }
+        self.reload_reviews();
+    }
+
+    fn review_problem(&mut self, id: i32, grade: Grade) {
+        let today = daily::today();
+        let current = self.reviews.get(&id).cloned().unwrap_or_else(|| review::initial(id, today));
+        let next = review::schedule(&current, grade, today);
+
+        if let Err(e) = self.db.record_review(&next) {
+            self.report(Severity::Error, "error.review", e, None);
+            return;
+        }
+        self.set_action(AppAction::Reviewed);
+        self.reload_reviews();
+    }
+
+    fn reload_reviews(&mut self) {
+        match self.db.get_reviews() {
+            Ok(reviews) => self.reviews = reviews.into_iter().map(|review| (review.problem, review)).collect(),
+            Err(e) => self.report(Severity::Warning, "error.review", e, None),
+        }
+    }
+
+    /// Збережені задачі, час повторення яких настав, — спершу найдавніші.
+    fn due_problems(&self) -> Vec<(db::Problem, db::Review)> {
+        let today = daily::today();
+        let mut due = self.saved_problems
+            .iter()
+            .filter_map(|problem| {
+                self.reviews.get(&problem.id)
+                    .filter(|review| review::is_due(review, today))
+                    .map(|review| (problem.clone(), review.clone()))
+            })
+            .collect::<Vec<_>>();
+        due.sort_by_key(|(problem, review)| (review.due, problem.id));
+        due
+    }
+
+    fn reload_contests(&mut self) {
+        match self.db.get_contests() {
+            Ok(contests) => self.contests = contests,
+            Err(e) => self.report(Severity::Warning, "error.contest", e, None),
+        }
+
+        match self.db.get_contest_problems() {
+            Ok(problems) => {
+                self.contest_problems.clear();
+                for problem in problems {
+                    self.contest_problems.entry(problem.contest).or_default().push(problem);
+                }
+            }
+            Err(e) => self.report(Severity::Warning, "error.contest", e, None),
+        }
+    }
+
+    fn active_contest(&self) -> Option<db::Contest> {
+        self.contests.iter().find(|contest| contest.finished_at.is_none()).cloned()
+    }
+
+    fn start_contest(&mut self) {
+        if self.contest_preparation.is_some() {
+            return;
+        }
+
+        let draft = self.contest_draft.clone();
+        let candidates = match draft.source {
+            Source::Random => contest::pick_random(&mut rand::rng(), &draft, &self.history),
+            Source::Saved => self.saved_problems
+                .iter()
+                .filter(|problem| draft.selected.contains(&problem.id))
+                .map(|problem| contest::Candidate {
+                    judge: problem_judge(problem),
+                    problem_id: problem.problem_id.clone(),
+                    name: problem.name.clone(),
+                })
+                .collect(),
+        };
+
+        if candidates.is_empty() {
+            let message = self.i18n.tr("toast.contest_empty").to_string();
+            self.notifications.push(Severity::Warning, message, None, None);
+            return;
+        }
+
+        // Збережені задачі та задачі з історії вже перевірені; решту таймер чекає
+        let mut preparation = Preparation::new(&draft, candidates);
+        for position in preparation.unchecked() {
+            self.fetch_contest_problem(&mut preparation, position);
+        }
+        self.continue_contest(preparation);
+    }
+
+    fn fetch_contest_problem(&mut self, preparation: &mut Preparation, position: usize) {
+        let Some(candidate) = preparation.candidate(position).cloned() else {
+            return;
+        };
+
+        self.next_request_id += 1;
+        let url = self.build_url(candidate.judge, &candidate.problem_id, self.locale);
+        let cancel = self.fetcher.fetch_title(self.next_request_id, candidate.judge, candidate.problem_id, url, self.locale);
+        preparation.track(self.next_request_id, position);
+        self.contest_fetches.push(cancel);
+    }
+
+    fn receive_contest_problem(&mut self, response: TitleResponse) {
+        let Some(mut preparation) = self.contest_preparation.take() else {
+            return;
+        };
+
+        match preparation.receive(&mut rand::rng(), response.request_id, response.result) {
+            Ok(Some(position)) => self.fetch_contest_problem(&mut preparation, position),
+            Ok(None) => {}
+            Err(e) => {
+                self.cancel_contest_preparation();
+                self.report(Severity::Warning, "error.contest_problems", e, None);
+                return;
+            }
+        }
+        self.continue_contest(preparation);
+    }
+
+    fn cancel_contest_preparation(&mut self) {
+        self.contest_preparation = None;
+        for cancel in self.contest_fetches.drain(..) {
+            cancel.cancel();
+        }
+    }
+
+    // Поки не всі назви відомі, контест чекає; далі він записується до бази й стартує таймер
+    fn continue_contest(&mut self, preparation: Preparation) {
+        if !preparation.is_ready() {
+            self.contest_preparation = Some(preparation);
+            return;
+        }
+        self.contest_fetches.clear();
+
+        let duration_minutes = preparation.duration_minutes;
+        let problems = preparation
+            .into_problems()
+            .into_iter()
+            .enumerate()
+            .map(|(position, candidate)| db::ContestProblem {
+                contest: 0,
+                position: position as i32,
+                judge: candidate.judge.code().to_string(),
+                url: self.build_url(candidate.judge, &candidate.problem_id, self.locale),
+                problem_id: candidate.problem_id,
+                name: candidate.name,
+                attempts: 0,
+                solved_minute: None,
+            })
+            .collect::<Vec<_>>();
+
+        let started_at = chrono::Utc::now().naive_utc();
+        if let Err(e) = self.db.start_contest(started_at, duration_minutes as i32, &problems) {
+            self.report(Severity::Error, "error.contest", e, None);
+            return;
+        }
+        self.contest_draft.selected.clear();
+        self.reload_contests();
+    }
+
+    /// Невдала спроба (`solved == false`) чи здача задачі на поточній хвилині контесту.
+    fn mark_contest_problem(&mut self, contest: &db::Contest, position: i32, solved: bool) {
+        let Some(mut problem) = self.contest_problems
+            .get(&contest.id)
+            .and_then(|problems| problems.iter().find(|problem| problem.position == position))
+            .cloned()
+        else {
+            return;
+        };
+
+        if solved {
+            problem.solved_minute = Some(contest::elapsed_minutes(contest, chrono::Utc::now().naive_utc()));
+        }
+        else {
+            problem.attempts += 1;
+        }
+
+        if let Err(e) = self.db.update_contest_problem(&problem) {
+            self.report(Severity::Error, "error.contest", e, None);
+            return;
+        }
+        self.reload_contests();
+        if self.show_stats {
+            self.reload_stats();
+        }
+    }
+
+    fn finish_contest(&mut self, id: i32) {
+        if let Err(e) = self.db.finish_contest(id, chrono::Utc::now().naive_utc()) {
+            self.report(Severity::Error, "error.contest", e, None);
+            return;
+        }
+        self.reload_contests();
+    }
+
+    // Час контесту спливає, навіть якщо вікно закрите
+    fn tick_contest(&mut self, ctx: &egui::Context) {
+        let Some(contest) = self.active_contest() else {
+            return;
+        };
+
+        let remaining = contest::remaining(&contest, chrono::Utc::now().naive_utc());
+        if remaining.is_zero() {
+            self.finish_contest(contest.id);
+            let message = self.i18n.tr("toast.contest_over").to_string();
+            self.notifications.push(Severity::Info, message, None, None);
+        }
+        else if self.show_contest {
+            ctx.request_repaint_after(Duration::from_secs(1));
+        }
+        else {
+            ctx.request_repaint_after(remaining.to_std().unwrap_or_default());
+        }
+    }
+
+    fn restore_tracker(&mut self) {
+        match self.db.get_open_session() {
+            Ok(Some(session)) => {
+                self.tracker = Some(Tracker {
+                    judge: Judge::from_code(&session.judge).unwrap_or_default(),
+                    problem_id: session.problem_id,
+                    session: Some((session.id, session.started_at)),
+                });
+            }
+            Ok(None) => {}
+            Err(e) => self.report(Severity::Warning, "error.timer", e, None),
+        }
+    }
+
+    /// Запускає таймер задачі; таймер іншої задачі при цьому ставиться на паузу.
+    fn start_timer(&mut self, judge: Judge, problem_id: &str) {
+        self.pause_timer();
+
+        let now = chrono::Utc::now().naive_utc();
+        match self.db.start_session(judge.code(), problem_id, now) {
+            Ok(id) => {
+                self.tracker = Some(Tracker {
+                    judge,
+                    problem_id: problem_id.to_string(),
+                    session: Some((id, now)),
+                });
+            }
+            Err(e) => self.report(Severity::Error, "error.timer", e, None),
+        }
+    }
+
+    fn pause_timer(&mut self) {
+        let Some((id, _)) = self.tracker.as_ref().and_then(|tracker| tracker.session) else {
+            return;
+        };
+
+        if let Err(e) = self.db.end_session(id, chrono::Utc::now().naive_utc()) {
+            self.report(Severity::Error, "error.timer", e, None);
+            return;
+        }
+        if let Some(tracker) = &mut self.tracker {
+            tracker.session = None;
+        }
+        self.reload_time_totals();
+    }
+
+    fn stop_timer(&mut self) {
+        self.pause_timer();
+        if self.tracker.as_ref().is_some_and(|tracker| !tracker.is_running()) {
+            self.tracker = None;
+        }
+    }
+
+    fn toggle_timer(&mut self, judge: Judge, problem_id: &str) {
+        let running = self.tracker
+            .as_ref()
+            .is_some_and(|tracker| tracker.tracks(judge, problem_id) && tracker.is_running());
+
+        if running {
+            self.pause_timer();
+        }
+        else {
+            self.start_timer(judge, problem_id);
+        }
+    }
+
+    fn reload_time_totals(&mut self) {
+        match self.db.get_time_per_problem() {
+            Ok(totals) => {
+                self.problem_time = totals
+                    .into_iter()
+                    .map(|total| ((total.judge, total.problem_id), total.seconds))
+                    .collect();
+            }
+            Err(e) => self.report(Severity::Warning, "error.timer", e, None),
+        }
+
+        let since = chrono::Utc::now().naive_utc() - chrono::Duration::days(tracking::DAYS_SHOWN);
+        match self.db.get_sessions_since(since) {
+            Ok(sessions) => self.work_sessions = sessions,
+            Err(e) => self.report(Severity::Warning, "error.timer", e, None),
+        }
+    }
+
+    /// Збережений час задачі разом із поточною сесією.
+    fn time_spent(&self, judge: Judge, problem_id: &str) -> i64 {
+        let saved = self.problem_time
+            .get(&(judge.code().to_string(), problem_id.to_string()))
+            .copied()
+            .unwrap_or_default();
+        let running = self.tracker
+            .as_ref()
+            .filter(|tracker| tracker.tracks(judge, problem_id))
+            .map_or(0, |tracker| tracker.running_seconds(chrono::Utc::now().naive_utc()));
+        saved + running
+    }
+
+    fn tick_timer(&self, ctx: &egui::Context) {
+        if self.tracker.as_ref().is_some_and(|tracker| tracker.is_running()) {
+            ctx.request_repaint_after(Duration::from_secs(1));
+        }
+    }
+
+    // Статистика рахується запитами до бази, тому лише при відкритті вікна чи на вимогу
+    fn reload_stats(&mut self) {
+        match self.load_stats() {
+            Ok(stats) => self.stats = stats,
+            Err(e) => self.report(Severity::Warning, "error.stats", e, None),
+        }
+    }
+
+    fn load_stats(&mut self) -> Result<Dashboard, diesel::result::Error> {
+        let today = daily::today();
+        Ok(Dashboard {
+            per_day: stats::fill_days(&self.db.get_solved_per_day(stats::DAYS_SHOWN as i64)?, today),
+            per_week: stats::fill_weeks(&self.db.get_solved_per_week(stats::WEEKS_SHOWN as i64)?, today),
+            difficulty: stats::fill_buckets(&self.db.get_solved_by_difficulty(stats::DIFFICULTY_BUCKET)?),
+            tags: self.db.get_solved_by_tag(stats::TAGS_SHOWN)?,
+            summary: self.db.get_solve_summary()?,
+        })
+    }
+
+    fn enqueue_fetch(&mut self, problem: &db::Problem, url: &str, locale: Locale) {
+        if let Err(e) = self.db.enqueue_fetch(problem, url, locale.code()) {
+            self.report(Severity::Warning, "error.queue", e, None);
+        }
+        self.reload_pending_fetches();
+    }
+
+    fn reload_pending_fetches(&mut self) {
+        match self.db.get_pending_fetches() {
+            Ok(pending) => self.pending_fetches = pending,
+            Err(e) => self.report(Severity::Warning, "error.queue", e, None),
+        }
+    }
+
+    fn replay_pending_fetches(&mut self) {
+        for pending in self.pending_fetches.clone() {
+            if self.replaying.values().any(|&id| id == pending.problem) {
+                continue;
+            }
+
+            let judge = Judge::from_code(&pending.judge).unwrap_or_default();
+            let locale = Locale::from_code(&pending.locale).unwrap_or_default();
+            self.next_request_id += 1;
+            self.fetcher.fetch_title(self.next_request_id, judge, pending.problem_id, pending.url, locale);
+            self.replaying.insert(self.next_request_id, pending.problem);
+        }
+    }
+
+    fn receive_pending_title(&mut self, id: i32, result: Result<String, FetchError>) {
+        match result {
+            Ok(title) => {
+                if let Err(e) = self.db.set_problem_name(id, &title) {
+                    self.report(Severity::Error, "error.save", e, None);
+                    return;
+                }
+
+                let is_current = self.pending_fetches.iter().any(|pending| {
+                    pending.problem == id
+                        && pending.judge == self.judge.code()
+                        && self.problem_id.as_deref() == Some(pending.problem_id.as_str())
+                });
+                if is_current && self.name.is_none() {
+                    self.name = Some(title);
+                }
+                self.replay_backoff = REPLAY_MIN_BACKOFF;
+            }
+            // Без мережі задача лишається в черзі до відновлення зв'язку
+            Err(e) if e.is_offline() => return,
+            // Сервер перевантажений чи обмежує запити — задача лишається в черзі до повтору
+            Err(e) if e.is_transient() => {
+                self.schedule_replay();
+                return;
+            }
+            // Такої сторінки немає, чекати на неї марно
+            Err(e) => self.report(Severity::Warning, "error.pending_title", e, None),
+        }
+
+        if let Err(e) = self.db.remove_pending_fetch(id) {
+            self.report(Severity::Warning, "error.queue", e, None);
+        }
+        self.reload_pending_fetches();
+        self.reload_problems();
+    }
+
+    fn schedule_replay(&mut self) {
+        if self.next_replay.is_none() {
+            self.next_replay = Some(Instant::now() + self.replay_backoff);
+            self.replay_backoff = (self.replay_backoff * 2).min(REPLAY_MAX_BACKOFF);
+        }
+    }
+
+    fn retry_pending_fetches(&mut self, ctx: &egui::Context) {
+        // Без мережі черга відтвориться, щойно зв'язок повернеться
+        if self.offline {
+            return;
+        }
+
+        match self.next_replay {
+            Some(at) if Instant::now() < at => ctx.request_repaint_after(at - Instant::now()),
+            Some(_) => {
+                self.next_replay = None;
+                self.replay_pending_fetches();
+            }
+            None => {}
+        }
+    }
+
+    // Стан зв'язку визначається за результатами звичайних запитів
+    fn update_connectivity<T>(&mut self, result: &Result<T, FetchError>) {
+        let reachable = !matches!(result, Err(e) if e.is_offline());
+
+        if reachable && self.offline {
+            self.offline = false;
+            self.next_probe = None;
+            if let Some((_, cancel)) = self.probe.take() {
+                cancel.cancel();
+            }
+            let message = self.i18n.tr("toast.online").to_string();
+            self.notifications.push(Severity::Info, message, None, None);
+
+            self.replay_pending_fetches();
+            if self.problem_id.is_some() && self.name.is_none() && !self.is_loading {
+                self.fetch_title();
+            }
+        }
+        else if !reachable && !self.offline {
+            self.offline = true;
+            self.next_probe = Some(Instant::now() + PROBE_INTERVAL);
+            let message = self.i18n.tr("toast.offline").to_string();
+            self.notifications.push(Severity::Warning, message, None, None);
+        }
+    }
+
+    fn probe_connection(&mut self, ctx: &egui::Context) {
+        if !self.offline || self.probe.is_some() {
+            return;
+        }
+
+        match self.next_probe {
+            Some(at) if Instant::now() < at => ctx.request_repaint_after(at - Instant::now()),
+            _ => {
+                self.next_request_id += 1;
+                let cancel = self.fetcher.check_connection(self.next_request_id, self.endpoints.base_url.clone());
+                self.probe = Some((self.next_request_id, cancel));
+            }
+        }
+    }
+
+    fn reload_problems(&mut self) {
+        match self.db.get_all_problems() {
+            Ok(problems) => self.saved_problems = problems,
+            Err(e) => self.report(Severity::Error, "error.reload", e, Some(RetryAction::ReloadProblems)),
+        }
+
+        match self.db.get_deleted_problems() {
+            Ok(problems) => self.deleted_problems = problems,
+            Err(e) => self.report(Severity::Error, "error.reload_trash", e, Some(RetryAction::ReloadProblems)),
+        }
+    }
+
+    fn delete_saved_problem(&mut self, id: i32) {
+        match self.db.delete_problem(id) {
+            Ok(_) => {
+                self.last_deleted = Some(id);
+                self.set_action(AppAction::Deleted);
+                self.reload_problems();
+            }
+            Err(e) => self.report(Severity::Error, "error.delete", e, Some(RetryAction::Delete(id))),
+        };
+    }
+
+    fn restore_problem(&mut self, id: i32) {
+        match self.db.restore_problem(id) {
+            Ok(_) => {
+                // Задача без розкладу (наприклад, з кошика до появи повторень) стає на повторення
+                self.schedule_review(id);
+                if self.last_deleted == Some(id) {
+                    self.last_deleted = None;
+                }
+                self.set_action(AppAction::Restored);
+                self.reload_problems();
+            }
+            Err(e) => self.report(Severity::Error, "error.restore", e, Some(RetryAction::Restore(id))),
+        }
+    }
+
+    fn undo_delete(&mut self) {
+        if let Some(id) = self.last_deleted.take() {
+            self.restore_problem(id);
+        }
+    }
+
+    fn purge_problem(&mut self, id: i32) {
+        match self.db.purge_problem(id) {
+            Ok(_) => {
+                self.reload_problems();
+                self.reload_reviews();
+            }
+            Err(e) => self.report(Severity::Error, "error.purge", e, Some(RetryAction::Purge(id))),
+        }
+    }
+
+    fn empty_trash(&mut self) {
+        match self.db.purge_deleted_problems() {
+            Ok(_) => {
+                self.last_deleted = None;
+                self.reload_problems();
+                self.reload_reviews();
+            }
+            Err(e) => self.report(Severity::Error, "error.empty_trash", e, Some(RetryAction::EmptyTrash)),
+        }
+    }
+
+    // Видалення можна скасувати довше, ніж живуть інші повідомлення
+    fn action_duration(action: AppAction) -> Duration {
+        match action {
+            AppAction::Deleted => UNDO_TIMEOUT,
+            _ => Duration::from_secs(1),
+        }
+    }
+
+    fn can_undo_delete(&self) -> bool {
+        matches!(self.last_action, Some(AppAction::Deleted))
+            && self.last_deleted.is_some()
+            && self.timestamp.is_some_and(|t| t.elapsed() < UNDO_TIMEOUT)
+    }
+
+    fn report(&mut self, severity: Severity, key: &str, details: impl std::fmt::Display, retry: Option<RetryAction>) {
+        let message = self.i18n.tr(key).to_string();
+        let details = details.to_string();
+
+        if let Err(e) = self.db.log_error(severity.code(), &message, Some(&details)) {
+            // Якщо не вдається навіть записати до журналу, лишається тільки stderr
+            eprintln!("{}: {} ({})", message, details, e);
+        }
+
+        self.notifications.push(severity, message, Some(details), retry);
+        self.reload_error_log();
+    }
+
+    fn reload_error_log(&mut self) {
+        match self.db.get_error_log() {
+            Ok(entries) => self.error_log = entries,
+            Err(e) => eprintln!("{}: {}", self.i18n.tr("error.log_load"), e),
+        }
+    }
+
+    fn clear_error_log(&mut self) {
+        match self.db.clear_error_log() {
+            Ok(_) => self.error_log.clear(),
+            Err(e) => self.report(Severity::Error, "error.log_clear", e, None),
+        }
+    }
+
+    fn retry(&mut self, action: RetryAction) {
+        match action {
+            RetryAction::Save => self.save(),
+            RetryAction::ReloadProblems => self.reload_problems(),
+            RetryAction::Delete(id) => self.delete_saved_problem(id),
+            RetryAction::Restore(id) => self.restore_problem(id),
+            RetryAction::Purge(id) => self.purge_problem(id),
+            RetryAction::EmptyTrash => self.empty_trash(),
+            RetryAction::OpenUrl(url) => self.open_url(url),
+        }
+    }
+
+    fn get_action_message(&self) -> Option<String> {
+        if let (Some(action), Some(timestamp)) = (self.last_action, self.timestamp)
+            && timestamp.elapsed() < Self::action_duration(action)
+        {
+            let key = match action {
+                AppAction::Generated => "action.generated",
+                AppAction::Opened => "action.opened",
+                AppAction::Copied => "action.copied",
+                AppAction::Saved => "action.saved",
+                AppAction::Deleted => "action.deleted",
+                AppAction::Restored => "action.restored",
+                AppAction::Reported => "action.reported",
+                AppAction::Reviewed => "action.reviewed",
+            };
+            return Some(self.i18n.tr(key).to_string());
+        }
+        None
+    }
+
+    fn set_action(&mut self, action: AppAction) {
+        self.last_action = Some(action);
+        self.timestamp = Some(Instant::now());
+    }
+
+    fn fetch_title(&mut self) {
+        // Попередній запит уже нікому не потрібен
+        self.cancel_fetch();
+
+        let Some(problem_id) = self.problem_id.clone() else {
+            return;
+        };
+
+        self.next_request_id += 1;
+        let request_id = self.next_request_id;
+        let cancel = self.fetcher.fetch_title(request_id, self.judge, problem_id, self.url.clone(), self.locale);
+
+        self.pending_fetch = Some((request_id, cancel));
+        self.name = None;
+        self.title_error = None;
+        self.is_loading = true;
+    }
+
+    fn load_http_config(&mut self) -> HttpConfig {
+        let mut config = HttpConfig::default();
+        let mut setting = |key: &str| self.db.get_setting(key).ok().flatten();
+
+        if let Some(secs) = setting(HTTP_TIMEOUT_SETTING).and_then(|v| v.parse().ok()) {
+            config.timeout = Duration::from_secs(secs);
+        }
+        if let Some(retries) = setting(HTTP_RETRIES_SETTING).and_then(|v| v.parse::<u32>().ok()) {
+            config.max_retries = retries.min(http::MAX_RETRIES);
+        }
+        if let Some(user_agent) = setting(HTTP_USER_AGENT_SETTING).filter(|v| !v.is_empty()) {
+            config.user_agent = user_agent;
+        }
+        config.proxy = setting(HTTP_PROXY_SETTING).filter(|v| !v.is_empty());
+        if let Some(hours) = setting(HTTP_CACHE_TTL_SETTING).and_then(|v| v.parse::<u64>().ok()) {
+            config.cache_ttl = Duration::from_secs(hours * 60 * 60);
+        }
+        if let Some(mb) = setting(HTTP_CACHE_SIZE_SETTING).and_then(|v| v.parse::<u64>().ok()) {
+            config.cache_max_bytes = mb * 1024 * 1024;
+        }
+
+        config
+    }
+
+    fn apply_http_config(&mut self, mut config: HttpConfig) {
+        // Запис і відтворення відповідей вмикаються лише змінною середовища
+        config.transport = TransportMode::from_env();
+        config.proxy = config.proxy
+            .map(|proxy| proxy.trim().to_string())
+            .filter(|proxy| !proxy.is_empty());
+
+        if let Err(e) = self.fetcher.configure(config.clone()) {
+            self.report(Severity::Error, "error.http_config", e, None);
+            self.http_draft = self.http_config.clone();
+            return;
+        }
+
+        let values = [
+            (HTTP_TIMEOUT_SETTING, config.timeout.as_secs().to_string()),
+            (HTTP_RETRIES_SETTING, config.max_retries.to_string()),
+            (HTTP_USER_AGENT_SETTING, config.user_agent.clone()),
+            (HTTP_PROXY_SETTING, config.proxy.clone().unwrap_or_default()),
+            (HTTP_CACHE_TTL_SETTING, (config.cache_ttl.as_secs() / 3600).to_string()),
+            (HTTP_CACHE_SIZE_SETTING, (config.cache_max_bytes / (1024 * 1024)).to_string()),
+        ];
+        for (key, value) in values {
+            if let Err(e) = self.db.set_setting(key, &value) {
+                self.report(Severity::Warning, "error.settings_save", e, None);
+            }
+        }
+
+        self.http_draft = config.clone();
+        self.http_config = config;
+    }
+
+    fn refill_prefetch(&mut self, ctx: &egui::Context) {
+        // У режимі задачі дня випадкові задачі не потрібні
+        if self.offline || self.daily_mode || self.seeded.is_some() {
+            return;
+        }
+
+        self.prefetch.refill(
+            &self.fetcher,
+            &mut self.next_request_id,
+            self.problem_id.as_deref().map(|id| (self.judge, id)),
+            |judge, id, locale| judge.problem_url(id, locale, &self.endpoints),
+        );
+
+        if let Some(delay) = self.prefetch.resume_in() {
+            ctx.request_repaint_after(delay);
+        }
+    }
+
+    fn set_title_source(&mut self, source: TitleSource) {
+        self.fetcher.set_source(source);
+
+        if let Err(e) = self.db.set_setting(TITLE_SOURCE_SETTING, source.code()) {
+            self.report(Severity::Warning, "error.settings_save", e, None);
+        }
+    }
+
+    fn set_prefetch_size(&mut self, size: usize) {
+        self.prefetch.set_size(size);
+
+        if let Err(e) = self.db.set_setting(PREFETCH_SIZE_SETTING, &size.to_string()) {
+            self.report(Severity::Warning, "error.settings_save", e, None);
+        }
+    }
+
+    fn clear_cache(&mut self) {
+        if let Err(e) = self.fetcher.clear_cache() {
+            self.report(Severity::Error, "error.cache_clear", e, None);
+        }
+        self.cache_size = None;
+    }
+
+    fn cancel_fetch(&mut self) {
+        if let Some((_, cancel)) = self.pending_fetch.take() {
+            cancel.cancel();
+        }
+        self.is_loading = false;
+    }
+
+    fn check_for_title(&mut self) {
+        while let Some(response) = self.fetcher.try_recv() {
+            if self.probe.as_ref().map(|(id, _)| *id) == Some(response.request_id) {
+                self.probe = None;
+                self.next_probe = Some(Instant::now() + PROBE_INTERVAL);
+                self.update_connectivity(&response.result);
+                continue;
+            }
+
+            if self.prefetch.owns(response.request_id) {
+                self.update_connectivity(&response.result);
+                self.prefetch.receive(response);
+                continue;
+            }
+
+            if let Some(problem_id) = self.replaying.remove(&response.request_id) {
+                self.update_connectivity(&response.result);
+                self.receive_pending_title(problem_id, response.result);
+                continue;
+            }
+
+            if self.contest_preparation.as_ref().is_some_and(|preparation| preparation.owns(response.request_id)) {
+                self.update_connectivity(&response.result);
+                self.receive_contest_problem(response);
+                continue;
+            }
+
+            // Відповіді на попередні Generate ігноруються
+            if self.pending_fetch.as_ref().map(|(id, _)| *id) != Some(response.request_id) {
+                continue;
+            }
+
+            self.pending_fetch = None;
+            self.is_loading = false;
+            self.update_connectivity(&response.result);
+
+            // Задачі дня не існує: уся команда детерміновано переходить до наступного кандидата
+            if let Some((date, attempt)) = self.daily
+                && let Some(next) = daily::next_attempt(&response.result, attempt)
+            {
+                self.show_daily_candidate(date, next);
+                self.fetch_title();
+                continue;
+            }
+
+            match response.result {
+                Ok(title) => {
+                    if let Some((date, attempt)) = self.daily {
+                        self.record_daily(date, attempt, title.clone());
+                    }
+                    self.record_shown(&title, &response.meta);
+                    self.name = Some(title);
+                }
+                // Без мережі це не помилка: назва завантажиться, коли зв'язок повернеться
+                Err(e) if e.is_offline() => {}
+                Err(e) => self.title_error = Some(e),
+            }
+        }
+    }
+}
+
+impl eframe::App for MyApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.check_for_title();
+        self.refill_prefetch(ctx);
+        self.probe_connection(ctx);
+        self.retry_pending_fetches(ctx);
+        self.tick_contest(ctx);
+        self.tick_timer(ctx);
+        self.update_theme(ctx);
+        self.handle_shortcuts(ctx);
+
+        egui::CentralPanel::default()
+            .frame(egui::Frame {
+                fill: self.theme.background,
+                ..Default::default()
+            })
+            .show(ctx, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(15.0);
+                    ui.heading(
+                        egui::RichText::new(self.i18n.tr("app.title"))
+                            .size(28.0)
+                    );
+                    ui.label(
+                        egui::RichText::new(self.i18n.tr("app.subtitle"))
+                            .size(14.0)
+                            .color(self.theme.text_secondary),
+                    );
+                    ui.add_space(10.0);
+
+                    ui.horizontal(|ui| {
+                        let total_width = 5.0 * 105.0 + 4.0 * ui.spacing().item_spacing.x;
+                        ui.add_space(((ui.available_width() - total_width) / 2.0).max(0.0));
+
+                        if ui.add(
+                            egui::Button::new(self.i18n.tr("settings.button"))
+                                .min_size(egui::vec2(105.0, 0.0))
+                        ).clicked() {
+                            self.show_settings = !self.show_settings;
+                        }
+
+                        if ui.add(
+                            egui::Button::new(self.i18n.tr("shortcuts.button"))
+                                .min_size(egui::vec2(105.0, 0.0))
+                        )
+                            .on_hover_text(keybindings::format_shortcut(&self.keybindings.get(Command::ShowShortcuts)))
+                            .clicked()
+                        {
+                            self.show_shortcuts = !self.show_shortcuts;
+                        }
+
+                        if ui.add(
+                            egui::Button::new(self.i18n.tr_args("log.button", &[("count", &self.error_log.len())]))
+                                .min_size(egui::vec2(105.0, 0.0))
+                        ).clicked() {
+                            self.show_error_log = !self.show_error_log;
+                        }
+
+                        if ui.add(
+                            egui::Button::new(self.i18n.tr("calendar.button"))
+                                .min_size(egui::vec2(105.0, 0.0))
+                        ).clicked() {
+                            self.show_calendar = !self.show_calendar;
+                            self.calendar_month = daily::today();
+                        }
+
+                        if ui.add(
+                            egui::Button::new(self.i18n.tr("contest.button"))
+                                .min_size(egui::vec2(105.0, 0.0))
+                        ).clicked() {
+                            self.show_contest = !self.show_contest;
+                        }
+                    });
+
+                    if self.offline {
+                        ui.add_space(8.0);
+                        ui.colored_label(
+                            self.theme.warning,
+                            self.i18n.tr_args("app.offline", &[("count", &self.pending_fetches.len())])
+                        );
+                    }
+                    ui.add_space(20.0);
+
+                    ui.vertical_centered(|ui| {
+                        self.render_main_section(ui, ctx);
+                        ui.add_space(20.0);
+                        self.render_info_section(ui, ctx);
+                        ui.add_space(10.0);
+                        self.render_action_feedback(ui, ctx);
+                    });
+
+                    ui.add_space(25.0);
+                    ui.separator();
+                    ui.add_space(15.0);
+
+                    self.render_saved_problems(ui, ctx);
+                });
+            });
+
+        self.render_settings_window(ctx);
+        self.render_shortcuts_overlay(ctx);
+        self.render_command_palette(ctx);
+        self.render_error_log(ctx);
+        self.render_calendar(ctx);
+        self.render_contest(ctx);
+        self.render_stats(ctx);
+        self.render_toasts(ctx);
+    }
+}
+
+impl MyApp {
+    fn render_settings_window(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_settings;
+        let mut selected_locale = self.locale;
+        let mut selected_language = self.i18n.language();
+        let mut selected_theme = self.theme_choice.clone();
+        let mut prefetch_size = self.prefetch.size();
+        let mut selected_source = self.fetcher.source();
+        let mut selected_judges = self.judges.clone();
+        let mut daily_mode = self.daily_mode;
+        let mut daily_salt = self.daily_salt.clone();
+        let mut weights = self.weights.clone();
+        let mut ladder = self.ladder;
+        let mut capture = None;
+        let mut reset_keybindings = false;
+        let mut apply_http = false;
+        let mut clear_cache = false;
+
+        // Розмір кешу рахується обходом каталогу, тому лише раз на відкриття вікна
+        let cache_size = if self.show_settings {
+            *self.cache_size.get_or_insert_with(|| self.fetcher.cache_size())
+        }
+        else {
+            self.cache_size = None;
+            0
+        };
+
+        egui::Window::new(self.i18n.tr("settings.title"))
+            .id(egui::Id::new("settings_window"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            // Налаштувань стало більше, ніж вміщує вікно
+            .vscroll(true)
+            .show(ctx, |ui| {
+                egui::Grid::new("settings_grid")
+                    .num_columns(2)
+                    .spacing([20.0, 10.0])
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(self.i18n.tr("settings.problem_locale"))
+                                .size(13.0)
+                                .color(self.theme.text)
+                        );
+                        egui::ComboBox::from_id_salt("locale")
+                            .width(150.0)
+                            .selected_text(selected_locale.label())
+                            .show_ui(ui, |ui| {
+                                for locale in Locale::ALL {
+                                    ui.selectable_value(&mut selected_locale, locale, locale.label());
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label(
+                            egui::RichText::new(self.i18n.tr("settings.ui_language"))
+                                .size(13.0)
+                                .color(self.theme.text)
+                        );
+                        egui::ComboBox::from_id_salt("language")
+                            .width(150.0)
+                            .selected_text(selected_language.label())
+                            .show_ui(ui, |ui| {
+                                for language in Language::ALL {
+                                    ui.selectable_value(&mut selected_language, language, language.label());
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label(
+                            egui::RichText::new(self.i18n.tr("settings.theme"))
+                                .size(13.0)
+                                .color(self.theme.text)
+                        );
+                        egui::ComboBox::from_id_salt("theme")
+                            .width(150.0)
+                            .selected_text(self.theme_label(&selected_theme))
+                            .show_ui(ui, |ui| {
+                                for choice in ThemeChoice::BUILTIN {
+                                    let label = self.theme_label(&choice);
+                                    ui.selectable_value(&mut selected_theme, choice, label);
+                                }
+                                for theme in self.themes.custom() {
+                                    ui.selectable_value(
+                                        &mut selected_theme,
+                                        ThemeChoice::Custom(theme.name.clone()),
+                                        &theme.name,
+                                    );
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label(
+                            egui::RichText::new(self.i18n.tr("settings.title_source"))
+                                .size(13.0)
+                                .color(self.theme.text)
+                        );
+                        egui::ComboBox::from_id_salt("title_source")
+                            .width(150.0)
+                            .selected_text(self.i18n.tr(selected_source.label_key()))
+                            .show_ui(ui, |ui| {
+                                for source in TitleSource::ALL {
+                                    ui.selectable_value(&mut selected_source, source, self.i18n.tr(source.label_key()));
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label(
+                            egui::RichText::new(self.i18n.tr("settings.prefetch"))
+                                .size(13.0)
+                                .color(self.theme.text)
+                        );
+                        ui.add(egui::DragValue::new(&mut prefetch_size).range(0..=prefetch::MAX_POOL_SIZE))
+                            .on_hover_text(self.i18n.tr("settings.prefetch.hover"));
+                        ui.end_row();
+
+                        ui.label(
+                            egui::RichText::new(self.i18n.tr("settings.judges"))
+                                .size(13.0)
+                                .color(self.theme.text)
+                        );
+                        ui.horizontal_wrapped(|ui| {
+                            for judge in Judge::ALL {
+                                let mut enabled = selected_judges.contains(&judge);
+                                // Хоча б один суддя має лишатися увімкненим
+                                let last = enabled && selected_judges.len() == 1;
+                                let checkbox = ui.add_enabled(!last, egui::Checkbox::new(&mut enabled, judge.label()));
+                                if checkbox.changed() {
+                                    selected_judges = Judge::ALL
+                                        .into_iter()
+                                        .filter(|&j| if j == judge { enabled } else { selected_judges.contains(&j) })
+                                        .collect();
+                                }
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label(
+                            egui::RichText::new(self.i18n.tr("settings.daily"))
+                                .size(13.0)
+                                .color(self.theme.text)
+                        );
+                        ui.checkbox(&mut daily_mode, "")
+                            .on_hover_text(self.i18n.tr("settings.daily.hover"));
+                        ui.end_row();
+
+                        ui.label(
+                            egui::RichText::new(self.i18n.tr("settings.daily_salt"))
+                                .size(13.0)
+                                .color(self.theme.text)
+                        );
+                        ui.add(egui::TextEdit::singleline(&mut daily_salt).desired_width(150.0))
+                            .on_hover_text(self.i18n.tr("settings.daily_salt.hover"));
+                        ui.end_row();
+                    });
+
+                ui.add_space(10.0);
+                ui.separator();
+                ui.add_space(5.0);
+
+                self.render_weights_settings(ui, &mut weights, &mut ladder);
+
+                ui.add_space(10.0);
+                ui.separator();
+                ui.add_space(5.0);
+
+                ui.label(
+                    egui::RichText::new(self.i18n.tr("settings.network"))
+                        .size(14.0)
+                        .strong()
+                        .color(self.theme.text)
+                );
+                ui.add_space(5.0);
+
+                ui.label(
+                    egui::RichText::new(self.i18n.tr_args("settings.network.site", &[("url", &self.endpoints.base_url)]))
+                        .size(12.0)
+                        .color(self.theme.text_muted)
+                );
+                ui.add_space(5.0);
+
+                egui::Grid::new("network_grid")
+                    .num_columns(2)
+                    .spacing([20.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label(self.i18n.tr("settings.network.timeout"));
+                        let mut secs = self.http_draft.timeout.as_secs();
+                        ui.add(egui::DragValue::new(&mut secs).range(1..=120).suffix(" s"));
+                        self.http_draft.timeout = Duration::from_secs(secs);
+                        ui.end_row();
+
+                        ui.label(self.i18n.tr("settings.network.retries"));
+                        ui.add(egui::DragValue::new(&mut self.http_draft.max_retries).range(0..=http::MAX_RETRIES));
+                        ui.end_row();
+
+                        ui.label(self.i18n.tr("settings.network.user_agent"));
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.http_draft.user_agent)
+                                .desired_width(220.0)
+                        );
+                        ui.end_row();
+
+                        ui.label(self.i18n.tr("settings.network.proxy"));
+                        ui.add(
+                            egui::TextEdit::singleline(self.http_draft.proxy.get_or_insert_with(String::new))
+                                .hint_text("http://proxy:8080")
+                                .desired_width(220.0)
+                        );
+                        ui.end_row();
+
+                        ui.label(self.i18n.tr("settings.network.cache_ttl"));
+                        let mut hours = self.http_draft.cache_ttl.as_secs() / 3600;
+                        ui.add(egui::DragValue::new(&mut hours).range(0..=24 * 30).suffix(" h"))
+                            .on_hover_text(self.i18n.tr("settings.network.cache_ttl.hover"));
+                        self.http_draft.cache_ttl = Duration::from_secs(hours * 3600);
+                        ui.end_row();
+
+                        ui.label(self.i18n.tr("settings.network.cache_size"));
+                        let mut mb = self.http_draft.cache_max_bytes / (1024 * 1024);
+                        ui.add(egui::DragValue::new(&mut mb).range(1..=1024).suffix(" MB"));
+                        self.http_draft.cache_max_bytes = mb * 1024 * 1024;
+                        ui.end_row();
+                    });
+
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    if ui.button(self.i18n.tr("settings.network.apply")).clicked() {
+                        apply_http = true;
+                    }
+                    if ui.button(self.i18n.tr("settings.network.reset")).clicked() {
+                        self.http_draft = HttpConfig::default();
+                        apply_http = true;
+                    }
+                    let used = format!("{:.1}", cache_size as f64 / (1024.0 * 1024.0));
+                    if ui.button(self.i18n.tr_args("settings.network.cache_clear", &[("size", &used)])).clicked() {
+                        clear_cache = true;
+                    }
+                });
+
+                ui.add_space(10.0);
+                ui.separator();
+                ui.add_space(5.0);
+
+                ui.label(
+                    egui::RichText::new(self.i18n.tr("settings.keybindings"))
+                        .size(14.0)
+                        .strong()
+                        .color(self.theme.text)
+                );
+                ui.add_space(5.0);
+
+                egui::Grid::new("keybindings_grid")
+                    .num_columns(2)
+                    .spacing([20.0, 6.0])
+                    .show(ui, |ui| {
+                        for command in Command::ALL {
+                            ui.label(
+                                egui::RichText::new(self.i18n.tr(command.label_key()))
+                                    .size(13.0)
+                                    .color(self.theme.text)
+                            );
+
+                            let text = if self.capturing == Some(command) {
+                                self.i18n.tr("settings.keybindings.capture").to_string()
+                            } else {
+                                keybindings::format_shortcut(&self.keybindings.get(command))
+                            };
+
+                            if ui.add(egui::Button::new(text).min_size(egui::vec2(150.0, 0.0))).clicked() {
+                                capture = Some(command);
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(5.0);
+                if ui.button(self.i18n.tr("settings.keybindings.reset")).clicked() {
+                    reset_keybindings = true;
+                }
+            });
+
+        self.show_settings = open;
+
+        if let Some(command) = capture {
+            self.capturing = Some(command);
+        }
+
+        if reset_keybindings {
+            self.reset_keybindings();
+        }
+
+        if selected_source != self.fetcher.source() {
+            self.set_title_source(selected_source);
+        }
+
+        if selected_judges != self.judges {
+            self.set_judges(selected_judges);
+        }
+
+        if weights != self.weights {
+            self.set_weights(weights);
+        }
+
+        if ladder != self.ladder {
+            self.set_ladder(ladder);
+        }
+
+        if daily_mode != self.daily_mode {
+            self.set_daily_mode(daily_mode);
+        }
+
+        if daily_salt != self.daily_salt {
+            self.set_daily_salt(daily_salt);
+        }
+
+        if prefetch_size != self.prefetch.size() {
+            self.set_prefetch_size(prefetch_size);
+        }
+
+        if clear_cache {
+            self.clear_cache();
+        }
+
+        if apply_http {
+            self.apply_http_config(self.http_draft.clone());
+        }
+
+        if selected_locale != self.locale {
+            self.set_locale(selected_locale);
+        }
+
+        if selected_language != self.i18n.language() {
+            self.set_language(selected_language);
+        }
+
+        if selected_theme != self.theme_choice {
+            self.set_theme_choice(selected_theme);
+        }
+    }
+
+    fn render_shortcuts_overlay(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_shortcuts;
+
+        egui::Window::new(self.i18n.tr("shortcuts.title"))
+            .id(egui::Id::new("shortcuts_window"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::vec2(0.0, 0.0))
+            .show(ctx, |ui| {
+                egui::Grid::new("shortcuts_grid")
+                    .num_columns(2)
+                    .spacing([30.0, 8.0])
+                    .show(ui, |ui| {
+                        for command in Command::ALL {
+                            ui.label(
+                                egui::RichText::new(self.i18n.tr(command.label_key()))
+                                    .size(13.0)
+                                    .color(self.theme.text)
+                            );
+                            ui.label(
+                                egui::RichText::new(keybindings::format_shortcut(&self.keybindings.get(command)))
+                                    .size(13.0)
+                                    .monospace()
+                                    .strong()
+                                    .color(self.theme.accent)
+                            );
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        self.show_shortcuts = open;
+    }
+
+    fn render_command_palette(&mut self, ctx: &egui::Context) {
+        let Some(mut palette) = self.palette.take() else {
+            return;
+        };
+        let items = self.palette_items(&palette.query);
+        let results = palette.filter(items);
+
+        let mut chosen = None;
+        let mut close = false;
+
+        ctx.input_mut(|input| {
+            if input.consume_key(egui::Modifiers::NONE, egui::Key::Escape) {
+                close = true;
+            }
+            if input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown) {
+                palette.selected += 1;
+            }
+            if input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp) {
+                palette.selected = palette.selected.saturating_sub(1);
+            }
+            if input.consume_key(egui::Modifiers::NONE, egui::Key::Enter) && !results.is_empty() {
+                chosen = Some(palette.selected.min(results.len() - 1));
+            }
+        });
+        palette.selected = palette.selected.min(results.len().saturating_sub(1));
+
+        egui::Window::new("command_palette")
+            .title_bar(false)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 80.0))
+            .show(ctx, |ui| {
+                let search = ui.add(
+                    egui::TextEdit::singleline(&mut palette.query)
+                        .hint_text(self.i18n.tr("palette.hint"))
+                        .desired_width(420.0)
+                );
+                search.request_focus();
+                if search.changed() {
+                    palette.selected = 0;
+                }
+
+                ui.separator();
+
+                if results.is_empty() {
+                    ui.label(
+                        egui::RichText::new(self.i18n.tr("palette.empty"))
+                            .size(13.0)
+                            .color(self.theme.text_muted)
+                    );
+                }
+
+                for (idx, item) in results.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        if ui.selectable_label(idx == palette.selected, &item.label).clicked() {
+                            chosen = Some(idx);
+                        }
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.label(
+                                egui::RichText::new(&item.hint)
+                                    .size(11.0)
+                                    .color(self.theme.text_muted)
+                            );
+                        });
+                    });
+                }
+            });
+
+        if let Some(idx) = chosen {
+            self.run_palette_action(ctx, results[idx].action.clone());
+        }
+        else if !close {
+            self.palette = Some(palette);
+        }
+    }
+
+    fn render_toasts(&mut self, ctx: &egui::Context) {
+        if let Some(next) = self.notifications.expire() {
+            ctx.request_repaint_after(next);
+        }
+
+        let mut to_dismiss = None;
+        let mut to_retry = None;
+
+        egui::Area::new(egui::Id::new("toasts"))
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-10.0, -10.0))
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.set_max_width(320.0);
+
+                for toast in self.notifications.toasts() {
+                    let color = self.severity_color(toast.severity);
+
+                    egui::Frame::popup(ui.style())
+                        .stroke(egui::Stroke::new(1.5, color))
+                        .show(ui, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.colored_label(
+                                    color,
+                                    egui::RichText::new(format!("{} {}", toast.severity.icon(), toast.message))
+                                        .size(13.0)
+                                        .strong()
+                                );
+
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    if ui.small_button("✖").clicked() {
+                                        to_dismiss = Some(toast.id);
+                                    }
+                                });
+                            });
+
+                            if let Some(details) = &toast.details {
+                                egui::CollapsingHeader::new(self.i18n.tr("toast.details"))
+                                    .id_salt(("toast_details", toast.id))
+                                    .show(ui, |ui| {
+                                        ui.label(
+                                            egui::RichText::new(details)
+                                                .size(11.0)
+                                                .monospace()
+                                                .color(self.theme.text_secondary)
+                                        );
+                                    });
+                            }
 
-        self.problem_id = Some(problem_id);
-        self.url = self.build_url(problem_id);
-        self.name = None;
-        self.is_loading = true;
-        self.set_action(AppAction::Generated);
+                            if let Some(retry) = &toast.retry
+                                && ui.button(self.i18n.tr("toast.retry")).clicked()
+                            {
+                                to_retry = Some((toast.id, retry.clone()));
+                            }
+                        });
 
-        self.fetch_title();
-    }
+                    ui.add_space(6.0);
+                }
+            });
 
-    fn build_url(&self, id: u32) -> String {
-        format!("{}/{}", BASE_URL, id)
-    }
+        if let Some(id) = to_dismiss {
+            self.notifications.dismiss(id);
+        }
 
-    fn open_url(&mut self, url: String) {
-        if let Err(e) = open::that(&url) {
-            eprintln!("Помилка при відкритті URL: {}", e);
+        if let Some((id, action)) = to_retry {
+            self.notifications.dismiss(id);
+            self.retry(action);
         }
-        self.set_action(AppAction::Opened);
     }
 
-    fn is_url_valid(&self) -> bool {
-        self.problem_id.is_some()
-    }
+    fn render_error_log(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_error_log;
+        let mut clear = false;
 
-    fn copy(&mut self, ctx: &egui::Context, url: String) {
-        ctx.copy_text(url);
-        self.set_action(AppAction::Copied);
-    }
+        egui::Window::new(self.i18n.tr("log.title"))
+            .id(egui::Id::new("error_log_window"))
+            .open(&mut open)
+            .collapsible(false)
+            .default_size(egui::vec2(480.0, 360.0))
+            .show(ctx, |ui| {
+                if ui.add_enabled(!self.error_log.is_empty(), egui::Button::new(self.i18n.tr("log.clear"))).clicked() {
+                    clear = true;
+                }
 
-    fn save(&mut self) {
-        if let (Some(id), Some(name)) = (self.problem_id, &self.name) {
-            match self.db.save_problem(id as i32, name.clone(), self.url.clone()) {
-                Ok(_) => {
-                    self.set_action(AppAction::Saved);
-                    self.reload_problems();
+                ui.separator();
+
+                if self.error_log.is_empty() {
+                    ui.label(
+                        egui::RichText::new(self.i18n.tr("log.empty"))
+                            .size(13.0)
+                            .color(self.theme.text_muted)
+                    );
+                    return;
                 }
-                Err(e) => eprintln!("Помилка при збереженні задачі: {:?}", e),
-            }
-        }
-    }
 
-    fn reload_problems(&mut self) {
-        match self.db.get_all_problems() {
-            Ok(problems) => self.saved_problems = problems,
-            Err(e) => eprintln!("Помилка при завантаженні задач: {:?}", e),
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false; 2])
+                    .show(ui, |ui| {
+                        for entry in &self.error_log {
+                            let severity = Severity::from_code(&entry.severity);
+                            let time = entry.created_at.and_utc().with_timezone(&chrono::Local);
+
+                            ui.horizontal(|ui| {
+                                ui.label(
+                                    egui::RichText::new(time.format("%Y-%m-%d %H:%M:%S").to_string())
+                                        .size(11.0)
+                                        .monospace()
+                                        .color(self.theme.text_muted)
+                                );
+                                ui.colored_label(
+                                    self.severity_color(severity),
+                                    egui::RichText::new(format!("{} {}", severity.icon(), entry.message))
+                                        .size(12.0)
+                                        .strong()
+                                );
+                            });
+
+                            if let Some(details) = &entry.details {
+                                ui.label(
+                                    egui::RichText::new(details)
+                                        .size(11.0)
+                                        .monospace()
+                                        .color(self.theme.text_secondary)
+                                );
+                            }
+
+                            ui.separator();
+                        }
+                    });
+            });
+
+        self.show_error_log = open;
+
+        if clear {
+            self.clear_error_log();
         }
     }
 
-    fn delete_saved_problem(&mut self, id: i32) {
-        match self.db.delete_problem(id) {
-            Ok(_) => {
-                self.set_action(AppAction::Deleted);
-                self.reload_problems();
+    fn render_weights_settings(&self, ui: &mut egui::Ui, weights: &mut Weights, ladder: &mut bool) {
+        ui.label(
+            egui::RichText::new(self.i18n.tr("settings.weights"))
+                .size(14.0)
+                .strong()
+                .color(self.theme.text)
+        );
+        ui.add_space(5.0);
+
+        ui.checkbox(ladder, self.i18n.tr("settings.ladder"))
+            .on_hover_text(self.i18n.tr("settings.ladder.hover"));
+        ui.add_enabled(!*ladder, egui::Checkbox::new(&mut weights.enabled, self.i18n.tr("settings.weights.enabled")))
+            .on_hover_text(self.i18n.tr("settings.weights.enabled.hover"));
+
+        ui.add_enabled_ui(weights.enabled || *ladder, |ui| {
+            egui::Grid::new("weights_grid")
+                .num_columns(2)
+                .spacing([20.0, 6.0])
+                .show(ui, |ui| {
+                    for (key, value) in [
+                        ("settings.weights.fresh", &mut weights.fresh),
+                        ("settings.weights.tags", &mut weights.tags),
+                        ("settings.weights.skipped", &mut weights.skipped),
+                        ("settings.weights.difficulty", &mut weights.difficulty),
+                    ] {
+                        ui.label(egui::RichText::new(self.i18n.tr(key)).size(13.0).color(self.theme.text));
+                        ui.add(egui::Slider::new(value, 0.0..=weights::MAX_WEIGHT).step_by(0.1));
+                        ui.end_row();
+                    }
+
+                    ui.label(egui::RichText::new(self.i18n.tr("settings.weights.target")).size(13.0).color(self.theme.text));
+                    if *ladder {
+                        ui.label(self.i18n.tr_args("settings.ladder.target", &[("target", &rating::target(self.rating()))]));
+                    }
+                    else {
+                        ui.add(egui::DragValue::new(&mut weights.target).range(800..=3500).speed(10));
+                    }
+                    ui.end_row();
+                });
+
+            ui.add_space(5.0);
+            ui.label(
+                egui::RichText::new(self.i18n.tr("settings.weights.preview"))
+                    .size(12.0)
+                    .color(self.theme.text_muted)
+            );
+
+            let mut effective = weights.clone();
+            if *ladder {
+                effective.target = rating::target(self.rating());
+            }
+            let distribution = weights::distribution(&effective, &self.history, chrono::Utc::now().naive_utc());
+            let mut rows = vec![(self.i18n.tr("settings.weights.fresh_problem").to_string(), distribution.fresh)];
+            for &(idx, probability) in distribution.known.iter().take(WEIGHTS_PREVIEW_ROWS) {
+                let entry = &self.history[idx];
+                let judge = Judge::from_code(&entry.judge).unwrap_or_default();
+                rows.push((format!("{} #{} {}", judge.label(), entry.problem_id, entry.name), probability));
             }
-            Err(e) => eprintln!("Помилка видалення задачі: {}", e),
-        };
-    }
 
-    fn get_action_message(&self) -> Option<String> {
-        if let (Some(action), Some(timestamp)) = (self.last_action, self.timestamp) {
-            if timestamp.elapsed() < Duration::from_secs(1) {
-                return Some(match action {
-                    AppAction::Generated => "✅ URL згенеровано!".to_string(),
-                    AppAction::Opened => "🌐 URL відкрито в браузері!".to_string(),
-                    AppAction::Copied => "📋 Скопійовано в буфер обміну!".to_string(),
-                    AppAction::Saved => "💾 Задачу збережено".to_string(),
-                    AppAction::Deleted => "🗑 Задачу видалено".to_string(),
-                })
+            for (label, probability) in rows {
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::ProgressBar::new(probability as f32)
+                            .desired_width(120.0)
+                            .text(format!("{:.1}%", probability * 100.0))
+                    );
+                    ui.label(egui::RichText::new(label).size(12.0).color(self.theme.text_secondary));
+                });
             }
-        }
-        None
-    }
 
-    fn set_action(&mut self, action: AppAction) {
-        self.last_action = Some(action);
-        self.timestamp = Some(Instant::now());
+            let hidden = distribution.known.len().saturating_sub(WEIGHTS_PREVIEW_ROWS);
+            if hidden > 0 {
+                ui.label(
+                    egui::RichText::new(self.i18n.tr_args("settings.weights.more", &[("count", &hidden)]))
+                        .size(11.0)
+                        .color(self.theme.text_muted)
+                );
+            }
+        });
     }
 
-    fn fetch_title(&self) {
-        let tx = self.tx.clone();
-        let url = self.url.clone();
+    fn render_calendar(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_calendar;
+        let mut month = self.calendar_month;
+        let mut chosen = None;
+        let today = daily::today();
 
-        thread::spawn(move || {
-            if let Some(title) = Self::get_problem_title(&url) {
-                let _ = tx.send(title);
-            }
-        });
+        egui::Window::new(self.i18n.tr("calendar.title"))
+            .id(egui::Id::new("calendar_window"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.small_button("◀").clicked() {
+                        month = daily::shift_month(month, false);
+                    }
+                    ui.label(
+                        egui::RichText::new(month.format("%m.%Y").to_string())
+                            .size(14.0)
+                            .strong()
+                            .color(self.theme.text)
+                    );
+                    let next = daily::shift_month(month, true);
+                    if ui.add_enabled(next <= today, egui::Button::new("▶").small()).clicked() {
+                        month = next;
+                    }
+                });
+
+                ui.add_space(5.0);
+
+                egui::Grid::new("calendar_grid")
+                    .num_columns(7)
+                    .spacing([4.0, 4.0])
+                    .show(ui, |ui| {
+                        for weekday in self.i18n.tr("calendar.weekdays").split(',') {
+                            ui.label(
+                                egui::RichText::new(weekday.trim())
+                                    .size(11.0)
+                                    .color(self.theme.text_muted)
+                            );
+                        }
+                        ui.end_row();
+
+                        for (idx, cell) in daily::month_grid(month).into_iter().enumerate() {
+                            match cell {
+                                Some(date) => {
+                                    let known = self.daily_problems.iter().find(|daily| daily.date == date);
+                                    let mut text = egui::RichText::new(date.day().to_string()).size(12.0);
+                                    if known.is_some() {
+                                        text = text.strong().color(self.theme.accent);
+                                    }
+
+                                    let button = egui::Button::new(text)
+                                        .min_size(egui::vec2(32.0, 24.0))
+                                        .selected(date == today);
+                                    let mut response = ui.add_enabled(date <= today, button);
+                                    if let Some(known) = known {
+                                        response = response.on_hover_text(format!(
+                                            "{} #{} {}",
+                                            Judge::from_code(&known.judge).unwrap_or_default().label(),
+                                            known.problem_id,
+                                            known.name
+                                        ));
+                                    }
+                                    if response.clicked() {
+                                        chosen = Some(date);
+                                    }
+                                }
+                                None => {
+                                    ui.label("");
+                                }
+                            }
+
+                            if idx % 7 == 6 {
+                                ui.end_row();
+                            }
+                        }
+                    });
+
+                ui.add_space(5.0);
+                ui.label(
+                    egui::RichText::new(self.i18n.tr("calendar.hint"))
+                        .size(11.0)
+                        .color(self.theme.text_muted)
+                );
+            });
+
+        self.show_calendar = open;
+        self.calendar_month = month;
+
+        if let Some(date) = chosen {
+            self.open_daily(date);
+        }
     }
 
-    fn get_problem_title(url: &str) -> Option<String> {
-        let html = reqwest::blocking::get(url).ok()?.text().ok()?;
-        let document = Html::parse_document(&html);
+    fn render_contest(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_contest;
+        let active = self.active_contest();
+        let mut draft = self.contest_draft.clone();
+        let mut start = false;
+        let mut cancel_preparation = false;
+        let mut finish = false;
+        let mut to_mark = None;
+        let mut to_open = None;
+
+        egui::Window::new(self.i18n.tr("contest.title"))
+            .id(egui::Id::new("contest_window"))
+            .open(&mut open)
+            .collapsible(false)
+            .default_size(egui::vec2(460.0, 420.0))
+            .vscroll(true)
+            .show(ctx, |ui| {
+                if let Some(contest) = &active {
+                    let problems = self.contest_problems.get(&contest.id).cloned().unwrap_or_default();
+                    let standing = contest::standing(&problems);
+                    let now = chrono::Utc::now().naive_utc();
+
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(self.i18n.tr_args(
+                                "contest.remaining",
+                                &[("time", &tracking::format_seconds(contest::remaining(contest, now).num_seconds()))],
+                            ))
+                                .size(18.0)
+                                .monospace()
+                                .strong()
+                                .color(self.theme.accent)
+                        );
+
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.button(self.i18n.tr("contest.finish")).clicked() {
+                                finish = true;
+                            }
+                        });
+                    });
+
+                    ui.label(self.i18n.tr_args(
+                        "contest.standing",
+                        &[("solved", &standing.solved), ("total", &problems.len()), ("penalty", &standing.penalty)],
+                    ));
+
+                    ui.separator();
+
+                    egui::Grid::new("contest_problems")
+                        .num_columns(4)
+                        .spacing([10.0, 6.0])
+                        .show(ui, |ui| {
+                            for problem in &problems {
+                                ui.label(
+                                    egui::RichText::new(contest::letter(problem.position).to_string())
+                                        .size(14.0)
+                                        .strong()
+                                        .color(self.theme.accent)
+                                );
+
+                                let title = format!(
+                                    "{} #{} {}",
+                                    Judge::from_code(&problem.judge).unwrap_or_default().label(),
+                                    problem.problem_id,
+                                    problem.name
+                                );
+                                if ui.link(title.trim_end()).clicked() {
+                                    to_open = Some(problem.url.clone());
+                                }
+
+                                ui.label(self.contest_verdict(problem));
+
+                                ui.horizontal(|ui| {
+                                    let pending = problem.solved_minute.is_none();
+                                    if ui.add_enabled(pending, egui::Button::new(self.i18n.tr("contest.attempt")).small()).clicked() {
+                                        to_mark = Some((problem.position, false));
+                                    }
+                                    if ui.add_enabled(pending, egui::Button::new(self.i18n.tr("contest.solved")).small()).clicked() {
+                                        to_mark = Some((problem.position, true));
+                                    }
+                                });
+                                ui.end_row();
+                            }
+                        });
+                    return;
+                }
+
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut draft.source, Source::Random, self.i18n.tr("contest.source.random"));
+                    ui.selectable_value(&mut draft.source, Source::Saved, self.i18n.tr("contest.source.saved"));
+                });
+
+                ui.add_space(5.0);
+
+                egui::Grid::new("contest_draft")
+                    .num_columns(2)
+                    .spacing([20.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label(self.i18n.tr("contest.duration"));
+                        ui.add(
+                            egui::DragValue::new(&mut draft.duration_minutes)
+                                .range(15..=contest::MAX_DURATION_MINUTES)
+                                .speed(5)
+                                .suffix(format!(" {}", self.i18n.tr("info.minutes")))
+                        );
+                        ui.end_row();
+
+                        if draft.source == Source::Random {
+                            ui.label(self.i18n.tr("contest.count"));
+                            ui.add(egui::DragValue::new(&mut draft.count).range(1..=contest::MAX_PROBLEMS));
+                            ui.end_row();
 
-        if let Ok(selector) = Selector::parse("title") {
-            if let Some(element) = document.select(&selector).next() {
-                let title = element.text().collect::<String>().trim().to_string();
-                if !title.is_empty() {
-                    return Some(title);
+                            ui.label(self.i18n.tr("settings.judges"));
+                            ui.horizontal_wrapped(|ui| {
+                                for judge in Judge::ALL {
+                                    let mut enabled = draft.judges.contains(&judge);
+                                    if ui.checkbox(&mut enabled, judge.label()).changed() {
+                                        if enabled {
+                                            draft.judges.push(judge);
+                                        }
+                                        else {
+                                            draft.judges.retain(|j| *j != judge);
+                                        }
+                                    }
+                                }
+                            });
+                            ui.end_row();
+
+                            let mut filter = draft.difficulty.is_some();
+                            let (mut min, mut max) = draft.difficulty.unwrap_or((800, 1600));
+                            ui.checkbox(&mut filter, self.i18n.tr("contest.difficulty"))
+                                .on_hover_text(self.i18n.tr("contest.difficulty.hover"));
+                            ui.horizontal(|ui| {
+                                ui.add_enabled(filter, egui::DragValue::new(&mut min).range(0..=max).speed(50));
+                                ui.label("—");
+                                ui.add_enabled(filter, egui::DragValue::new(&mut max).range(min..=4000).speed(50));
+                            });
+                            draft.difficulty = filter.then_some((min, max));
+                            ui.end_row();
+                        }
+                    });
+
+                if draft.source == Source::Saved {
+                    ui.add_space(5.0);
+                    ui.label(self.i18n.tr_args("contest.selected", &[("count", &draft.selected.len())]));
+                    egui::ScrollArea::vertical()
+                        .id_salt("contest_saved")
+                        .max_height(160.0)
+                        .show(ui, |ui| {
+                            for problem in &self.saved_problems {
+                                let mut picked = draft.selected.contains(&problem.id);
+                                let enabled = picked || draft.selected.len() < contest::MAX_PROBLEMS;
+                                let label = format!("{} #{} {}", problem_judge(problem).label(), problem.problem_id, problem.name);
+                                if ui.add_enabled(enabled, egui::Checkbox::new(&mut picked, label.trim_end())).changed() {
+                                    if picked {
+                                        draft.selected.push(problem.id);
+                                    }
+                                    else {
+                                        draft.selected.retain(|id| *id != problem.id);
+                                    }
+                                }
+                            }
+                        });
+                }
+
+                ui.add_space(8.0);
+
+                let ready = match draft.source {
+                    Source::Random => !draft.judges.is_empty(),
+                    Source::Saved => !draft.selected.is_empty(),
+                };
+                if let Some(preparation) = &self.contest_preparation {
+                    let (checked, total) = preparation.progress();
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(self.i18n.tr_args("contest.preparing", &[("checked", &checked), ("total", &total)]));
+                        if ui.small_button(self.i18n.tr("info.cancel")).clicked() {
+                            cancel_preparation = true;
+                        }
+                    });
+                }
+                else if ui.add_enabled(ready, egui::Button::new(self.i18n.tr("contest.start"))).clicked() {
+                    start = true;
+                }
+
+                ui.separator();
+
+                ui.label(
+                    egui::RichText::new(self.i18n.tr("contest.history"))
+                        .size(14.0)
+                        .strong()
+                );
+                if self.contests.is_empty() {
+                    ui.label(
+                        egui::RichText::new(self.i18n.tr("contest.history.empty"))
+                            .size(12.0)
+                            .color(self.theme.text_muted)
+                    );
+                }
+                for past in &self.contests {
+                    let problems = self.contest_problems.get(&past.id).map(Vec::as_slice).unwrap_or_default();
+                    let standing = contest::standing(problems);
+                    let started = past.started_at.and_utc().with_timezone(&chrono::Local);
+                    let header = self.i18n.tr_args(
+                        "contest.summary",
+                        &[
+                            ("date", &started.format("%Y-%m-%d %H:%M")),
+                            ("solved", &standing.solved),
+                            ("total", &problems.len()),
+                            ("penalty", &standing.penalty),
+                        ],
+                    );
+
+                    egui::CollapsingHeader::new(header)
+                        .id_salt(("contest", past.id))
+                        .show(ui, |ui| {
+                            for problem in problems {
+                                ui.horizontal(|ui| {
+                                    ui.label(
+                                        egui::RichText::new(contest::letter(problem.position).to_string())
+                                            .strong()
+                                            .color(self.theme.accent)
+                                    );
+                                    let title = format!(
+                                        "{} #{} {}",
+                                        Judge::from_code(&problem.judge).unwrap_or_default().label(),
+                                        problem.problem_id,
+                                        problem.name
+                                    );
+                                    if ui.link(title.trim_end()).clicked() {
+                                        to_open = Some(problem.url.clone());
+                                    }
+                                    ui.label(self.contest_verdict(problem));
+                                });
+                            }
+                        });
                 }
+            });
+
+        self.show_contest = open;
+        self.contest_draft = draft;
+
+        if start {
+            self.start_contest();
+        }
+        if cancel_preparation {
+            self.cancel_contest_preparation();
+        }
+
+        if let Some(contest) = &active {
+            if let Some((position, solved)) = to_mark {
+                self.mark_contest_problem(contest, position, solved);
+            }
+            if finish {
+                self.finish_contest(contest.id);
             }
         }
-        None
-    }
 
-    fn check_for_title(&mut self) {
-        if let Ok(title) = self.rx.try_recv() {
-            self.name = Some(title);
-            self.is_loading = false;
+        if let Some(url) = to_open {
+            self.open_url(url);
         }
     }
-}
 
-impl eframe::App for MyApp {
-    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
-        self.check_for_title();
+    fn render_stats(&mut self, ctx: &egui::Context) {
+        let mut open = self.show_stats;
+        let mut refresh = false;
+        let stats = &self.stats;
+        let summary = &stats.summary;
 
-        egui::CentralPanel::default()
-            .frame(egui::Frame {
-                fill: egui::Color32::from_rgb(15, 15, 15),
-                ..Default::default()
-            })
+        egui::Window::new(self.i18n.tr("stats.title"))
+            .id(egui::Id::new("stats_window"))
+            .open(&mut open)
+            .collapsible(false)
+            .default_size(egui::vec2(460.0, 520.0))
+            .vscroll(true)
             .show(ctx, |ui| {
-                ui.vertical_centered(|ui| {
-                    ui.add_space(15.0);
-                    ui.heading(
-                        egui::RichText::new("🔗 Eolymp Problem Generator")
-                            .size(28.0)
-                    );
-                    ui.label(
-                        egui::RichText::new("Знайди випадкову задачу для розв'язання")
-                            .size(14.0)
-                            .color(egui::Color32::from_rgb(150, 150, 150)),
-                    );
-                    ui.add_space(20.0);
+                if ui.button(self.i18n.tr("stats.refresh")).clicked() {
+                    refresh = true;
+                }
 
-                    ui.vertical_centered(|ui| {
-                        self.render_main_section(ui, ctx);
-                        ui.add_space(20.0);
-                        self.render_info_section(ui, ctx);
-                        ui.add_space(10.0);
-                        self.render_action_feedback(ui, ctx);
+                ui.add_space(5.0);
+
+                egui::Grid::new("stats_summary")
+                    .num_columns(2)
+                    .spacing([20.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label(self.i18n.tr("stats.solved"));
+                        ui.colored_label(self.theme.accent, summary.solved.to_string());
+                        ui.end_row();
+
+                        ui.label(self.i18n.tr("stats.saved_solved"));
+                        ui.horizontal(|ui| {
+                            ui.label(format!("{} / {}", summary.saved_solved, summary.saved));
+                            if let Some(ratio) = stats::solved_ratio(summary) {
+                                ui.add(
+                                    egui::ProgressBar::new(ratio)
+                                        .desired_width(120.0)
+                                        .show_percentage()
+                                );
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label(self.i18n.tr("stats.average"));
+                        match summary.average_minutes {
+                            Some(minutes) => ui.label(self.i18n.tr_args(
+                                "stats.average.value",
+                                &[("minutes", &format!("{:.0}", minutes)), ("count", &summary.timed)],
+                            )),
+                            None => ui.colored_label(self.theme.text_muted, self.i18n.tr("stats.no_data")),
+                        };
+                        ui.end_row();
                     });
 
-                    ui.add_space(25.0);
-                    ui.separator();
-                    ui.add_space(15.0);
+                ui.separator();
+                self.stats_heading(ui, &self.i18n.tr_args("stats.per_day", &[("days", &stats::DAYS_SHOWN)]));
+                let bars = stats.per_day
+                    .iter()
+                    .map(|(day, count)| (day.format("%d.%m").to_string(), *count))
+                    .collect::<Vec<_>>();
+                self.bar_chart(ui, &bars);
 
-                    self.render_saved_problems(ui, ctx);
-                });
+                self.stats_heading(ui, &self.i18n.tr_args("stats.per_week", &[("weeks", &stats::WEEKS_SHOWN)]));
+                let bars = stats.per_week
+                    .iter()
+                    .map(|(day, count)| (day.format("%d.%m").to_string(), *count))
+                    .collect::<Vec<_>>();
+                self.bar_chart(ui, &bars);
+
+                self.stats_heading(ui, self.i18n.tr("stats.difficulty"));
+                let bars = stats.difficulty
+                    .iter()
+                    .map(|(bucket, count)| (bucket.to_string(), *count))
+                    .collect::<Vec<_>>();
+                self.bar_chart(ui, &bars);
+
+                self.stats_heading(ui, self.i18n.tr("stats.tags"));
+                if stats.tags.is_empty() {
+                    ui.colored_label(self.theme.text_muted, self.i18n.tr("stats.no_data"));
+                }
+                let max = stats.tags.iter().map(|tag| tag.count).max().unwrap_or(1).max(1);
+                egui::Grid::new("stats_tags")
+                    .num_columns(2)
+                    .spacing([10.0, 4.0])
+                    .show(ui, |ui| {
+                        for tag in &stats.tags {
+                            ui.label(egui::RichText::new(&tag.tag).size(12.0));
+                            ui.add(
+                                egui::ProgressBar::new(tag.count as f32 / max as f32)
+                                    .desired_width(200.0)
+                                    .fill(self.theme.accent)
+                                    .text(tag.count.to_string())
+                            );
+                            ui.end_row();
+                        }
+                    });
             });
+
+        self.show_stats = open;
+        if refresh {
+            self.reload_stats();
+        }
+    }
+
+    fn stats_heading(&self, ui: &mut egui::Ui, text: &str) {
+        ui.add_space(8.0);
+        ui.label(
+            egui::RichText::new(text)
+                .size(14.0)
+                .strong()
+                .color(self.theme.text)
+        );
+        ui.add_space(4.0);
+    }
+
+    // Стовпчикова діаграма на painter; точне значення видно при наведенні
+    fn bar_chart(&self, ui: &mut egui::Ui, bars: &[(String, i64)]) {
+        if bars.iter().all(|(_, count)| *count == 0) {
+            ui.colored_label(self.theme.text_muted, self.i18n.tr("stats.no_data"));
+            return;
+        }
+
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(ui.available_width(), 90.0), egui::Sense::hover());
+        let max = bars.iter().map(|(_, count)| *count).max().unwrap_or(1).max(1);
+        let slot = rect.width() / bars.len() as f32;
+        let painter = ui.painter();
+
+        painter.line_segment([rect.left_bottom(), rect.right_bottom()], egui::Stroke::new(1.0, self.theme.text_faint));
+        for (idx, (_, count)) in bars.iter().enumerate() {
+            let left = rect.left() + slot * idx as f32;
+            let height = rect.height() * *count as f32 / max as f32;
+            let bar = egui::Rect::from_min_max(
+                egui::pos2(left + slot * 0.15, rect.bottom() - height),
+                egui::pos2(left + slot * 0.85, rect.bottom()),
+            );
+            painter.rect_filled(bar, 2.0, self.theme.accent);
+        }
+
+        if let Some(pos) = response.hover_pos() {
+            let idx = (((pos.x - rect.left()) / slot) as usize).min(bars.len() - 1);
+            let (label, count) = &bars[idx];
+            response.on_hover_text(format!("{}: {}", label, count));
+        }
+
+        ui.horizontal(|ui| {
+            let (first, last) = (&bars[0].0, &bars[bars.len() - 1].0);
+            ui.label(egui::RichText::new(first).size(10.0).color(self.theme.text_muted));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                ui.label(egui::RichText::new(last).size(10.0).color(self.theme.text_muted));
+            });
+        });
+    }
+
+    // Позначення як у таблицях ICPC: «+1 (42)» — здано з другої спроби на 42-й хвилині
+    fn contest_verdict(&self, problem: &db::ContestProblem) -> egui::RichText {
+        match problem.solved_minute {
+            Some(minute) => {
+                let attempts = if problem.attempts > 0 { format!("+{}", problem.attempts) } else { "+".to_string() };
+                egui::RichText::new(format!("{} ({})", attempts, minute))
+                    .monospace()
+                    .color(self.theme.success)
+            }
+            None if problem.attempts > 0 => egui::RichText::new(format!("-{}", problem.attempts))
+                .monospace()
+                .color(self.theme.error),
+            None => egui::RichText::new("·")
+                .monospace()
+                .color(self.theme.text_muted),
+        }
+    }
+
+    fn severity_color(&self, severity: Severity) -> egui::Color32 {
+        match severity {
+            Severity::Info => self.theme.info,
+            Severity::Warning => self.theme.warning,
+            Severity::Error => self.theme.error,
+        }
+    }
+
+    // Задачі, збережені без мережі, показуються з позначкою замість назви
+    fn problem_name_text(&self, problem: &db::Problem) -> egui::RichText {
+        if problem.name.is_empty() {
+            egui::RichText::new(self.i18n.tr("saved.pending_title")).italics()
+        }
+        else {
+            egui::RichText::new(&problem.name)
+        }
+    }
+
+    fn theme_label(&self, choice: &ThemeChoice) -> String {
+        match (choice.label_key(), choice) {
+            (Some(key), _) => self.i18n.tr(key).to_string(),
+            (None, ThemeChoice::Custom(name)) => name.clone(),
+            (None, _) => choice.code(),
+        }
     }
-}
 
-impl MyApp {
     fn render_main_section(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
         let button_width = 110.0;
         let button_height = 45.0;
@@ -246,16 +3096,19 @@ impl MyApp {
             // Generate button
             if ui.add(
                 egui::Button::new(
-                    egui::RichText::new("🎲 Generate")
+                    egui::RichText::new(self.i18n.tr("button.generate"))
                         .size(13.0)
-                        .color(egui::Color32::WHITE)
+                        .color(self.theme.button_text)
                         .strong()
                 )
-                    .fill(egui::Color32::from_rgb(200, 100, 255))
+                    .fill(self.theme.generate_fill)
                     .min_size(egui::vec2(button_width, button_height))
-                    .stroke(egui::Stroke::new(2.0, egui::Color32::from_rgb(220, 150, 255)))
+                    .stroke(egui::Stroke::new(2.0, self.theme.generate_stroke))
             )
-                .on_hover_text("Натисни щоб згенерувати нову задачу")
+                .on_hover_text(self.i18n.tr_args(
+                    "button.generate.hover",
+                    &[("ready", &self.prefetch.ready_count()), ("size", &self.prefetch.size())],
+                ))
                 .clicked()
             {
                 self.generate_url();
@@ -265,16 +3118,16 @@ impl MyApp {
             if ui.add_enabled(
                 self.is_url_valid(),
                 egui::Button::new(
-                    egui::RichText::new("🌐 Open")
+                    egui::RichText::new(self.i18n.tr("button.open"))
                         .size(13.0)
-                        .color(egui::Color32::WHITE)
+                        .color(self.theme.button_text)
                         .strong()
                 )
-                    .fill(egui::Color32::from_rgb(100, 200, 150))
+                    .fill(self.theme.open_fill)
                     .min_size(egui::vec2(button_width, button_height))
-                    .stroke(egui::Stroke::new(2.0, egui::Color32::from_rgb(150, 255, 200)))
+                    .stroke(egui::Stroke::new(2.0, self.theme.open_stroke))
             )
-                .on_hover_text("Відкрити задачу у браузері")
+                .on_hover_text(self.i18n.tr("button.open.hover"))
                 .clicked()
             {
                 self.open_url(self.url.clone());
@@ -284,16 +3137,16 @@ impl MyApp {
             if ui.add_enabled(
                 self.is_url_valid(),
                 egui::Button::new(
-                    egui::RichText::new("📋 Copy")
+                    egui::RichText::new(self.i18n.tr("button.copy"))
                         .size(13.0)
-                        .color(egui::Color32::WHITE)
+                        .color(self.theme.button_text)
                         .strong()
                 )
-                    .fill(egui::Color32::from_rgb(255, 180, 100))
+                    .fill(self.theme.copy_fill)
                     .min_size(egui::vec2(button_width, button_height))
-                    .stroke(egui::Stroke::new(2.0, egui::Color32::from_rgb(255, 220, 150)))
+                    .stroke(egui::Stroke::new(2.0, self.theme.copy_stroke))
             )
-                .on_hover_text("Скопіювати URL у буфер")
+                .on_hover_text(self.i18n.tr("button.copy.hover"))
                 .clicked()
             {
                 self.copy(ctx, self.url.clone());
@@ -303,16 +3156,16 @@ impl MyApp {
             if ui.add_enabled(
                 self.is_url_valid(),
                 egui::Button::new(
-                    egui::RichText::new("💾 Save")
+                    egui::RichText::new(self.i18n.tr("button.save"))
                         .size(13.0)
-                        .color(egui::Color32::WHITE)
+                        .color(self.theme.button_text)
                         .strong()
                 )
-                    .fill(egui::Color32::from_rgb(100, 100, 100))
+                    .fill(self.theme.save_fill)
                     .min_size(egui::vec2(button_width, button_height))
-                    .stroke(egui::Stroke::new(2.0, egui::Color32::from_rgb(255, 220, 150)))
+                    .stroke(egui::Stroke::new(2.0, self.theme.save_stroke))
             )
-                .on_hover_text("Зберігає задачу")
+                .on_hover_text(self.i18n.tr("button.save.hover"))
                 .clicked()
             {
                 self.save();
@@ -320,7 +3173,18 @@ impl MyApp {
         });
     }
 
-    fn render_info_section(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+    fn render_info_section(&mut self, ui: &mut egui::Ui, _ctx: &egui::Context) {
+        let mut cancel = false;
+        let mut retry = false;
+        let mut start_seed = false;
+        let mut stop_seed = false;
+        let mut copied_seed = false;
+        let mut report = None;
+        let mut report_minutes = self.report_minutes;
+        let mut toggle_timer = false;
+        let mut stop_timer = false;
+        let mut open_stats = false;
+
         ui.group(|ui| {
             ui.set_width(ui.available_width() * 0.8);
 
@@ -329,31 +3193,52 @@ impl MyApp {
             // Title
             ui.horizontal(|ui| {
                 ui.label(
-                    egui::RichText::new("📝 Назва:")
+                    egui::RichText::new(self.i18n.tr("info.name"))
                         .size(13.0)
                         .strong()
-                        .color(egui::Color32::from_rgb(200, 200, 200))
+                        .color(self.theme.text)
                 );
 
                 if self.is_loading {
                     ui.add_space(5.0);
                     ui.spinner();
                     ui.colored_label(
-                        egui::Color32::YELLOW,
-                        egui::RichText::new("⏳ Завантаження...")
+                        self.theme.loading,
+                        egui::RichText::new(self.i18n.tr("info.loading"))
                             .size(13.0)
                     );
+
+                    if ui.small_button(self.i18n.tr("info.cancel")).clicked() {
+                        cancel = true;
+                    }
+                }
+                else if let Some(error) = &self.title_error {
+                    ui.colored_label(
+                        self.theme.error,
+                        egui::RichText::new(self.i18n.tr("info.fetch_failed")).size(12.0)
+                    )
+                        .on_hover_text(error.to_string());
+
+                    if ui.small_button(self.i18n.tr("info.retry")).clicked() {
+                        retry = true;
+                    }
                 }
                 else if let Some(name) = &self.name {
                     ui.colored_label(
-                        egui::Color32::from_rgb(150, 200, 255),
+                        self.theme.problem_name,
                         egui::RichText::new(name).size(12.0)
                     );
                 }
+                else if self.offline && self.problem_id.is_some() {
+                    ui.colored_label(
+                        self.theme.warning,
+                        egui::RichText::new(self.i18n.tr("info.offline")).size(12.0)
+                    );
+                }
                 else {
                     ui.colored_label(
-                        egui::Color32::DARK_GRAY,
-                        egui::RichText::new("(---)").size(12.0)
+                        self.theme.placeholder,
+                        egui::RichText::new(self.i18n.tr("info.empty")).size(12.0)
                     );
                 }
             });
@@ -365,22 +3250,22 @@ impl MyApp {
             // URL
             ui.horizontal(|ui| {
                 ui.label(
-                    egui::RichText::new("🔗 URL")
+                    egui::RichText::new(self.i18n.tr("info.url"))
                         .size(13.0)
                         .strong()
-                        .color(egui::Color32::from_rgb(200, 200, 200))
+                        .color(self.theme.text)
                 );
 
                 if self.url.is_empty() {
                     ui.colored_label(
-                        egui::Color32::DARK_GRAY,
-                        egui::RichText::new("(---)")
+                        self.theme.placeholder,
+                        egui::RichText::new(self.i18n.tr("info.empty"))
                             .size(12.0)
                     );
                 }
                 else {
                     ui.colored_label(
-                        egui::Color32::from_rgb(150, 200, 150),
+                        self.theme.problem_url,
                         egui::RichText::new(&self.url)
                             .size(12.0)
                     );
@@ -391,48 +3276,616 @@ impl MyApp {
             ui.separator();
             ui.add_space(8.0);
 
+            if let Some((date, _)) = self.daily {
+                ui.colored_label(
+                    self.theme.accent,
+                    egui::RichText::new(self.i18n.tr_args("info.daily", &[("date", &date.format("%Y-%m-%d"))]))
+                        .size(13.0)
+                        .strong()
+                );
+                ui.add_space(4.0);
+            }
+
             // ID
             ui.horizontal(|ui| {
                 ui.label(
-                    egui::RichText::new("📌 ID Задачі:")
+                    egui::RichText::new(self.i18n.tr("info.id"))
+                        .size(13.0)
+                        .strong()
+                        .color(self.theme.text)
+                );
+
+                if let Some(id) = &self.problem_id {
+                    ui.colored_label(
+                        self.theme.accent,
+                        format!("{} #{}", self.judge.label(), id)
+                    );
+                }
+                else {
+                    ui.colored_label(self.theme.placeholder, self.i18n.tr("info.empty"));
+                }
+            });
+
+            ui.add_space(4.0);
+
+            // Seed
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(self.i18n.tr("info.seed"))
                         .size(13.0)
                         .strong()
-                        .color(egui::Color32::from_rgb(200, 200, 200))
+                        .color(self.theme.text)
                 );
 
-                if let Some(id) = self.problem_id {
+                if let Some(run) = &self.seeded {
+                    let code = run.code().encode();
+                    ui.colored_label(self.theme.accent, egui::RichText::new(&code).monospace());
                     ui.colored_label(
-                        egui::Color32::from_rgb(200, 150, 255),
-                        format!("#{}", id)
+                        self.theme.text_muted,
+                        self.i18n.tr_args("info.seed.position", &[("position", &run.position())])
                     );
+
+                    if ui.small_button("📋").on_hover_text(self.i18n.tr("info.seed.copy")).clicked() {
+                        ui.ctx().copy_text(code);
+                        copied_seed = true;
+                    }
+                    if ui.small_button(self.i18n.tr("info.seed.stop")).clicked() {
+                        stop_seed = true;
+                    }
                 }
                 else {
-                    ui.colored_label(egui::Color32::DARK_GRAY, "(---)");
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.seed_input)
+                            .hint_text(self.i18n.tr("info.seed.hint"))
+                            .desired_width(160.0)
+                    );
+                    if ui.small_button(self.i18n.tr("info.seed.start")).clicked() {
+                        start_seed = true;
+                    }
+                }
+            });
+
+            ui.add_space(4.0);
+
+            // Rating
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(self.i18n.tr("info.rating"))
+                        .size(13.0)
+                        .strong()
+                        .color(self.theme.text)
+                );
+                ui.colored_label(self.theme.accent, format!("{:.0}", self.rating()));
+
+                if let Some(last) = self.rating_history.last() {
+                    let delta = last.rating_after - last.rating_before;
+                    let color = if delta >= 0.0 { self.theme.success } else { self.theme.error };
+                    ui.colored_label(color, format!("({:+.0})", delta));
+                }
+                self.rating_sparkline(ui);
+
+                if ui.small_button(self.i18n.tr("stats.button")).clicked() {
+                    open_stats = true;
+                }
+            });
+
+            let current = self.problem_id.as_ref().map(|id| (self.judge, id.clone()));
+            let can_report = current.is_some() && current != self.reported;
+            ui.horizontal(|ui| {
+                ui.add_enabled_ui(can_report, |ui| {
+                    if ui.small_button(self.i18n.tr("info.solved")).clicked() {
+                        report = Some(true);
+                    }
+                    if ui.small_button(self.i18n.tr("info.failed")).clicked() {
+                        report = Some(false);
+                    }
+                    ui.add(
+                        egui::DragValue::new(&mut report_minutes)
+                            .range(0..=600)
+                            .suffix(format!(" {}", self.i18n.tr("info.minutes")))
+                    )
+                        .on_hover_text(self.i18n.tr("info.minutes.hover"));
+                });
+            });
+
+            ui.add_space(4.0);
+
+            // Timer
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(self.i18n.tr("info.timer"))
+                        .size(13.0)
+                        .strong()
+                        .color(self.theme.text)
+                );
+
+                let Some((judge, id)) = &current else {
+                    ui.colored_label(self.theme.placeholder, self.i18n.tr("info.empty"));
+                    return;
+                };
+
+                let tracked = self.tracker.as_ref().filter(|tracker| tracker.tracks(*judge, id));
+                let running = tracked.is_some_and(|tracker| tracker.is_running());
+                ui.colored_label(
+                    if running { self.theme.accent } else { self.theme.text_secondary },
+                    egui::RichText::new(tracking::format_seconds(self.time_spent(*judge, id))).monospace()
+                );
+
+                let (icon, hover) = if running { ("⏸", "timer.pause") } else { ("▶", "timer.start") };
+                if ui.small_button(icon).on_hover_text(self.i18n.tr(hover)).clicked() {
+                    toggle_timer = true;
+                }
+                if ui.add_enabled(tracked.is_some(), egui::Button::new("⏹").small())
+                    .on_hover_text(self.i18n.tr("timer.stop"))
+                    .clicked()
+                {
+                    stop_timer = true;
                 }
             });
 
+            self.render_time_per_day(ui);
+
             ui.add_space(8.0);
         });
+
+        if cancel {
+            self.cancel_fetch();
+        }
+
+        if retry {
+            self.fetch_title();
+        }
+
+        if copied_seed {
+            self.set_action(AppAction::Copied);
+        }
+
+        if stop_seed {
+            self.stop_seeded_run();
+        }
+
+        if start_seed {
+            let text = self.seed_input.clone();
+            self.start_seeded_run(&text);
+        }
+
+        if let Some(id) = self.problem_id.clone() {
+            if toggle_timer {
+                self.toggle_timer(self.judge, &id);
+            }
+            if stop_timer {
+                self.stop_timer();
+            }
+        }
+
+        if open_stats {
+            self.show_stats = !self.show_stats;
+            if self.show_stats {
+                self.reload_stats();
+            }
+        }
+
+        self.report_minutes = report_minutes;
+        if let Some(solved) = report {
+            self.report_result(solved);
+        }
+    }
+
+    // Незавершена сесія додається до сьогоднішнього дня
+    fn render_time_per_day(&self, ui: &mut egui::Ui) {
+        let now = chrono::Utc::now().naive_utc();
+        let mut sessions = self.work_sessions.clone();
+        if let Some((_, started_at)) = self.tracker.as_ref().and_then(|tracker| tracker.session) {
+            sessions.push((started_at, now));
+        }
+        let sessions = sessions
+            .into_iter()
+            .map(|(started_at, ended_at)| (tracking::local_time(started_at), tracking::local_time(ended_at)))
+            .collect::<Vec<_>>();
+        let days = tracking::time_per_day(&sessions, daily::today());
+
+        egui::CollapsingHeader::new(self.i18n.tr_args("timer.days", &[("days", &tracking::DAYS_SHOWN)]))
+            .id_salt("time_per_day")
+            .show(ui, |ui| {
+                if days.is_empty() {
+                    ui.colored_label(self.theme.text_muted, self.i18n.tr("timer.days.empty"));
+                }
+
+                for (day, seconds) in days {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            egui::RichText::new(day.format("%Y-%m-%d").to_string())
+                                .size(12.0)
+                                .color(self.theme.text_secondary)
+                        );
+                        ui.label(
+                            egui::RichText::new(tracking::format_seconds(seconds))
+                                .size(12.0)
+                                .monospace()
+                        );
+                    });
+                }
+            });
+    }
+
+    // Рейтинг за останні звіти тонкою лінією поруч із числом
+    fn rating_sparkline(&self, ui: &mut egui::Ui) {
+        let ratings = self.rating_history
+            .iter()
+            .rev()
+            .take(SPARKLINE_POINTS)
+            .rev()
+            .map(|change| change.rating_after)
+            .collect::<Vec<_>>();
+        if ratings.len() < 2 {
+            return;
+        }
+
+        let (rect, response) = ui.allocate_exact_size(egui::vec2(80.0, 16.0), egui::Sense::hover());
+        let min = ratings.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = ratings.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let span = (max - min).max(1.0);
+
+        let points = ratings
+            .iter()
+            .enumerate()
+            .map(|(idx, rating)| {
+                let x = rect.left() + rect.width() * idx as f32 / (ratings.len() - 1) as f32;
+                let y = rect.bottom() - rect.height() * ((rating - min) / span) as f32;
+                egui::pos2(x, y)
+            })
+            .collect();
+        ui.painter().add(egui::Shape::line(points, egui::Stroke::new(1.5, self.theme.accent)));
+
+        response.on_hover_text(format!("{:.0} – {:.0}", min, max));
     }
 
     fn render_action_feedback(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
         if let Some(message) = self.get_action_message() {
             ui.colored_label(
-                egui::Color32::GREEN,
+                self.theme.success,
                 egui::RichText::new(message)
                     .size(13.0)
                     .strong()
             );
+
+            if self.can_undo_delete() && ui.button(self.i18n.tr("action.undo")).clicked() {
+                self.undo_delete();
+            }
+
+            // Перемальовуємо вікно, щоб повідомлення зникло вчасно навіть без вводу
+            if let (Some(action), Some(timestamp)) = (self.last_action, self.timestamp) {
+                ctx.request_repaint_after(Self::action_duration(action).saturating_sub(timestamp.elapsed()));
+            }
+        }
+    }
+
+    fn render_trash(&mut self, ui: &mut egui::Ui) {
+        ui.label(
+            egui::RichText::new(self.i18n.tr_args("trash.heading", &[("count", &self.deleted_problems.len())]))
+                .size(16.0)
+                .strong()
+        );
+
+        ui.add_space(5.0);
+
+        ui.horizontal(|ui| {
+            let width = 2.0 * 170.0 + ui.spacing().item_spacing.x;
+            ui.add_space(((ui.available_width() - width) / 2.0).max(0.0));
+
+            if ui.add(
+                egui::Button::new(self.i18n.tr("trash.back"))
+                    .min_size(egui::vec2(170.0, 0.0))
+            ).clicked() {
+                self.show_trash = false;
+                self.confirm_empty_trash = false;
+            }
+
+            if self.confirm_empty_trash {
+                if ui.add(
+                    egui::Button::new(self.i18n.tr("trash.empty.confirm"))
+                        .min_size(egui::vec2(170.0, 0.0))
+                ).clicked() {
+                    self.empty_trash();
+                    self.confirm_empty_trash = false;
+                }
+            }
+            else if ui.add_enabled(
+                !self.deleted_problems.is_empty(),
+                egui::Button::new(self.i18n.tr("trash.empty"))
+                    .min_size(egui::vec2(170.0, 0.0))
+            ).clicked() {
+                self.confirm_empty_trash = true;
+            }
+        });
+
+        ui.add_space(10.0);
+
+        if self.deleted_problems.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(20.0);
+                ui.heading(
+                    egui::RichText::new("🗑")
+                        .size(48.0)
+                );
+                ui.label(
+                    egui::RichText::new(self.i18n.tr("trash.no_items"))
+                        .size(14.0)
+                        .color(self.theme.text_muted)
+                );
+                ui.add_space(20.0);
+            });
+            return;
+        }
+
+        let mut to_restore = None;
+        let mut to_purge = None;
+
+        egui::ScrollArea::vertical()
+            .id_salt("trash")
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                for (idx, problem) in self.deleted_problems.iter().enumerate() {
+                    ui.group(|ui| {
+                        ui.add_space(8.0);
+
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+
+                            ui.vertical(|ui| {
+                                // ID
+                                ui.label(
+                                    egui::RichText::new(format!("{} #{}", problem_judge(problem).label(), problem.problem_id))
+                                        .size(12.0)
+                                        .color(self.theme.accent)
+                                        .strong()
+                                );
+
+                                // Name
+                                ui.label(
+                                    self.problem_name_text(problem)
+                                        .size(13.0)
+                                        .color(self.theme.text_secondary)
+                                        .strong()
+                                );
+
+                                // Deletion time
+                                if let Some(deleted_at) = problem.deleted_at {
+                                    let local = deleted_at.and_utc().with_timezone(&chrono::Local);
+                                    ui.label(
+                                        egui::RichText::new(self.i18n.tr_args(
+                                            "trash.deleted_at",
+                                            &[("time", &local.format("%Y-%m-%d %H:%M"))],
+                                        ))
+                                            .size(10.0)
+                                            .color(self.theme.text_muted)
+                                    );
+                                }
+                            });
+
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                ui.add_space(5.0);
+
+                                // Purge button
+                                if ui.button(
+                                    egui::RichText::new("❌")
+                                        .size(16.0)
+                                )
+                                    .on_hover_text(self.i18n.tr("trash.purge.hover"))
+                                    .clicked()
+                                {
+                                    to_purge = Some(problem.id);
+                                }
+
+                                ui.add_space(5.0);
+
+                                // Restore button
+                                if ui.button(
+                                    egui::RichText::new("♻")
+                                        .size(16.0)
+                                )
+                                    .on_hover_text(self.i18n.tr("trash.restore.hover"))
+                                    .clicked()
+                                {
+                                    to_restore = Some(problem.id);
+                                }
+
+                                ui.add_space(10.0);
+                            });
+                        });
+
+                        ui.add_space(8.0);
+                    });
+
+                    if idx < self.deleted_problems.len() - 1 {
+                        ui.add_space(8.0);
+                    }
+                }
+            });
+
+        if let Some(id) = to_restore {
+            self.restore_problem(id);
+        }
+
+        if let Some(id) = to_purge {
+            self.purge_problem(id);
+        }
+    }
+
+    fn render_reviews(&mut self, ui: &mut egui::Ui) {
+        let due = self.due_problems();
+
+        ui.label(
+            egui::RichText::new(self.i18n.tr_args("review.heading", &[("count", &due.len())]))
+                .size(16.0)
+                .strong()
+        );
+
+        ui.add_space(5.0);
+
+        ui.vertical_centered(|ui| {
+            if ui.add(
+                egui::Button::new(self.i18n.tr("trash.back"))
+                    .min_size(egui::vec2(170.0, 0.0))
+            ).clicked() {
+                self.show_reviews = false;
+            }
+        });
+
+        ui.add_space(10.0);
+
+        if due.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(20.0);
+                ui.heading(
+                    egui::RichText::new("🎉")
+                        .size(48.0)
+                );
+                ui.label(
+                    egui::RichText::new(self.i18n.tr("review.no_items"))
+                        .size(14.0)
+                        .color(self.theme.text_muted)
+                );
+                ui.add_space(20.0);
+            });
+            return;
+        }
+
+        let mut to_open = None;
+        let mut to_grade = None;
+
+        egui::ScrollArea::vertical()
+            .id_salt("reviews")
+            .auto_shrink([false; 2])
+            .show(ui, |ui| {
+                for (idx, (problem, review)) in due.iter().enumerate() {
+                    ui.group(|ui| {
+                        ui.add_space(8.0);
+
+                        ui.horizontal(|ui| {
+                            ui.add_space(10.0);
+
+                            ui.vertical(|ui| {
+                                // ID
+                                ui.label(
+                                    egui::RichText::new(format!("{} #{}", problem_judge(problem).label(), problem.problem_id))
+                                        .size(12.0)
+                                        .color(self.theme.accent)
+                                        .strong()
+                                );
+
+                                // Name
+                                ui.label(
+                                    self.problem_name_text(problem)
+                                        .size(13.0)
+                                        .color(self.theme.text)
+                                        .strong()
+                                );
+
+                                // Schedule
+                                let last = review.last_grade
+                                    .as_deref()
+                                    .and_then(Grade::from_code)
+                                    .map_or_else(|| self.i18n.tr("review.never").to_string(), |grade| self.i18n.tr(grade.label_key()).to_string());
+                                ui.label(
+                                    egui::RichText::new(self.i18n.tr_args(
+                                        "review.details",
+                                        &[
+                                            ("date", &review.due.format("%Y-%m-%d")),
+                                            ("interval", &review.interval_days),
+                                            ("last", &last),
+                                        ],
+                                    ))
+                                        .size(10.0)
+                                        .color(self.theme.text_muted)
+                                );
+                            });
+
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                ui.add_space(5.0);
+
+                                for grade in Grade::ALL.into_iter().rev() {
+                                    if ui.button(self.i18n.tr(grade.label_key())).clicked() {
+                                        to_grade = Some((problem.id, grade));
+                                    }
+                                }
+
+                                ui.add_space(5.0);
+
+                                // Open button
+                                if ui.button(
+                                    egui::RichText::new("🔗")
+                                        .size(16.0)
+                                )
+                                    .on_hover_text(self.i18n.tr("saved.open.hover"))
+                                    .clicked()
+                                {
+                                    to_open = Some(problem.url.clone());
+                                }
+
+                                ui.add_space(10.0);
+                            });
+                        });
+
+                        ui.add_space(8.0);
+                    });
+
+                    if idx < due.len() - 1 {
+                        ui.add_space(8.0);
+                    }
+                }
+            });
+
+        if let Some(url) = to_open {
+            self.open_url(url);
+        }
+
+        if let Some((id, grade)) = to_grade {
+            self.review_problem(id, grade);
         }
     }
 
     fn render_saved_problems(&mut self, ui: &mut egui::Ui, ctx: &egui::Context) {
+        if self.show_trash {
+            self.render_trash(ui);
+            return;
+        }
+        if self.show_reviews {
+            self.render_reviews(ui);
+            return;
+        }
+
         ui.label(
-            egui::RichText::new(format!("💾 Збережені задачі, ({})", self.saved_problems.len()))
+            egui::RichText::new(self.i18n.tr_args("saved.heading", &[("count", &self.saved_problems.len())]))
                 .size(16.0)
                 .strong()
         );
 
+        ui.add_space(5.0);
+
+        ui.horizontal(|ui| {
+            if ui.button(self.i18n.tr_args("trash.button", &[("count", &self.deleted_problems.len())])).clicked() {
+                self.show_trash = true;
+            }
+
+            if ui.button(self.i18n.tr_args("review.button", &[("count", &self.due_problems().len())])).clicked() {
+                self.show_reviews = true;
+            }
+        });
+
+        ui.add_space(10.0);
+
+        let search = ui.add(
+            egui::TextEdit::singleline(&mut self.search)
+                .id(egui::Id::new(SEARCH_ID))
+                .hint_text(self.i18n.tr("saved.search.hint"))
+                .desired_width(ui.available_width() * 0.8)
+        );
+        if self.focus_search {
+            search.request_focus();
+            self.focus_search = false;
+        }
+
         ui.add_space(10.0);
 
         if self.saved_problems.is_empty() {
@@ -443,14 +3896,14 @@ impl MyApp {
                         .size(48.0)
                 );
                 ui.label(
-                    egui::RichText::new("Немає збережених задач")
+                    egui::RichText::new(self.i18n.tr("saved.empty"))
                         .size(14.0)
-                        .color(egui::Color32::from_rgb(100, 100, 100))
+                        .color(self.theme.text_muted)
                 );
                 ui.label(
-                    egui::RichText::new("Згенеруй задачу та натисни 💾 для збереження")
+                    egui::RichText::new(self.i18n.tr("saved.empty.hint"))
                         .size(12.0)
-                        .color(egui::Color32::from_rgb(80, 80, 80))
+                        .color(self.theme.text_faint)
                 );
                 ui.add_space(20.0);
             });
@@ -459,31 +3912,46 @@ impl MyApp {
             let mut to_delete = None;
             let mut to_open = None;
             let mut to_copy = None;
+            let mut to_relocale = None;
+            let mut to_select = None;
+            let mut to_time = None;
+            let mut to_stop = false;
+            let visible = self.saved_problems
+                .iter()
+                .filter(|problem| self.matches_search(problem))
+                .collect::<Vec<_>>();
+
             egui::ScrollArea::vertical()
                 .auto_shrink([false; 2])
                 .show(ui, |ui| {
-                    for (idx, problem) in self.saved_problems.iter().enumerate() {
-                        ui.group(|ui| {
+                    for (idx, problem) in visible.iter().enumerate() {
+                        let selected = self.selected_problem == Some(problem.id);
+                        let mut card = egui::Frame::group(ui.style());
+                        if selected {
+                            card = card.stroke(egui::Stroke::new(2.0, self.theme.accent));
+                        }
+
+                        card.show(ui, |ui| {
                             ui.add_space(8.0);
 
                             ui.horizontal(|ui| {
                                 ui.add_space(10.0);
 
                                 // Problem info
-                                ui.vertical(|ui| {
+                                let info = ui.vertical(|ui| {
                                     // ID
                                     ui.label(
-                                        egui::RichText::new(format!("#{}", problem.problem_id))
+                                        egui::RichText::new(format!("{} #{}", problem_judge(problem).label(), problem.problem_id))
                                             .size(12.0)
-                                            .color(egui::Color32::from_rgb(200, 150, 255))
+                                            .color(self.theme.accent)
                                             .strong()
                                     );
 
                                     // Name
                                     ui.label(
-                                        egui::RichText::new(&problem.name)
+                                        self.problem_name_text(problem)
                                             .size(13.0)
-                                            .color(egui::Color32::from_rgb(200, 200, 200))
+                                            .color(self.theme.text)
                                             .strong()
                                     );
 
@@ -491,11 +3959,45 @@ impl MyApp {
                                     ui.label(
                                         egui::RichText::new(&problem.url)
                                             .size(10.0)
-                                            .color(egui::Color32::from_rgb(100, 150, 200))
+                                            .color(self.theme.link)
                                             .strong()
                                     );
+
+                                    // Time spent
+                                    let judge = problem_judge(problem);
+                                    let spent = self.time_spent(judge, &problem.problem_id);
+                                    if spent > 0 {
+                                        ui.label(
+                                            egui::RichText::new(self.i18n.tr_args(
+                                                "timer.spent",
+                                                &[("time", &tracking::format_seconds(spent))],
+                                            ))
+                                                .size(10.0)
+                                                .color(self.theme.text_muted)
+                                        );
+                                    }
+
+                                    // Next review
+                                    if let Some(review) = self.reviews.get(&problem.id) {
+                                        ui.label(
+                                            egui::RichText::new(self.i18n.tr_args(
+                                                "review.next",
+                                                &[("date", &review.due.format("%Y-%m-%d"))],
+                                            ))
+                                                .size(10.0)
+                                                .color(self.theme.text_muted)
+                                        );
+                                    }
                                 });
 
+                                if info.response
+                                    .interact(egui::Sense::click())
+                                    .on_hover_cursor(egui::CursorIcon::PointingHand)
+                                    .clicked()
+                                {
+                                    to_select = Some(problem.id);
+                                }
+
                                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                     ui.add_space(5.0);
 
@@ -504,10 +4006,43 @@ impl MyApp {
                                         egui::RichText::new("🗑")
                                             .size(16.0)
                                     )
-                                        .on_hover_text("Видалити задачу")
+                                        .on_hover_text(self.i18n.tr("saved.delete.hover"))
+                                        .clicked()
+                                    {
+                                        to_delete = Some(problem.id);
+                                    }
+
+                                    ui.add_space(5.0);
+
+                                    // Timer buttons
+                                    let judge = problem_judge(problem);
+                                    let tracked = self.tracker
+                                        .as_ref()
+                                        .filter(|tracker| tracker.tracks(judge, &problem.problem_id));
+                                    if tracked.is_some() {
+                                        if ui.button(
+                                            egui::RichText::new("⏹")
+                                                .size(16.0)
+                                        )
+                                            .on_hover_text(self.i18n.tr("timer.stop"))
+                                            .clicked()
+                                        {
+                                            to_stop = true;
+                                        }
+
+                                        ui.add_space(5.0);
+                                    }
+
+                                    let running = tracked.is_some_and(|tracker| tracker.is_running());
+                                    let (icon, hover) = if running { ("⏸", "timer.pause") } else { ("⏱", "timer.start") };
+                                    if ui.button(
+                                        egui::RichText::new(icon)
+                                            .size(16.0)
+                                    )
+                                        .on_hover_text(self.i18n.tr(hover))
                                         .clicked()
                                     {
-                                        to_delete = Some(problem.problem_id);
+                                        to_time = Some((judge, problem.problem_id.clone()));
                                     }
 
                                     ui.add_space(5.0);
@@ -517,7 +4052,7 @@ impl MyApp {
                                         egui::RichText::new("📋")
                                             .size(16.0)
                                     )
-                                        .on_hover_text("Копіювати URL")
+                                        .on_hover_text(self.i18n.tr("saved.copy.hover"))
                                         .clicked()
                                     {
                                         to_copy = Some(problem.url.clone());
@@ -530,12 +4065,36 @@ impl MyApp {
                                         egui::RichText::new("🔗")
                                             .size(16.0)
                                     )
-                                        .on_hover_text("Відкрити в браузері")
+                                        .on_hover_text(self.i18n.tr("saved.open.hover"))
                                         .clicked()
                                     {
                                         to_open = Some(problem.url.clone());
                                     }
 
+                                    ui.add_space(5.0);
+
+                                    // Locale override
+                                    let current = problem.locale
+                                        .as_deref()
+                                        .and_then(Locale::from_code)
+                                        .unwrap_or_default();
+                                    let mut selected = current;
+
+                                    egui::ComboBox::from_id_salt(("problem_locale", problem.id))
+                                        .width(50.0)
+                                        .selected_text(selected.code())
+                                        .show_ui(ui, |ui| {
+                                            for locale in Locale::ALL {
+                                                ui.selectable_value(&mut selected, locale, locale.label());
+                                            }
+                                        })
+                                        .response
+                                        .on_hover_text(self.i18n.tr("saved.locale.hover"));
+
+                                    if selected != current {
+                                        to_relocale = Some((problem.id, selected));
+                                    }
+
                                     ui.add_space(10.0);
                                 });
                             });
@@ -543,7 +4102,7 @@ impl MyApp {
                             ui.add_space(8.0);
                         });
 
-                        if idx < self.saved_problems.len() - 1 {
+                        if idx < visible.len() - 1 {
                             ui.add_space(8.0);
                         }
                     };
@@ -560,6 +4119,26 @@ impl MyApp {
             if let Some(url) = to_copy {
                 self.copy(ctx, url);
             }
+
+            if let Some((id, locale)) = to_relocale {
+                self.set_problem_locale(id, locale);
+            }
+
+            if let Some((judge, id)) = to_time {
+                self.toggle_timer(judge, &id);
+            }
+
+            if to_stop {
+                self.stop_timer();
+            }
+
+            if let Some(id) = to_select {
+                self.selected_problem = if self.selected_problem == Some(id) { None } else { Some(id) };
+            }
         }
     }
-}
\ No newline at end of file
+}
+
+fn problem_judge(problem: &db::Problem) -> Judge {
+    Judge::from_code(&problem.judge).unwrap_or_default()
+}
@@ -0,0 +1,114 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+// Більше сповіщень одночасно лише захаращує екран, старі витісняються
+const MAX_TOASTS: usize = 5;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn code(self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "info" => Severity::Info,
+            "warning" => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+
+    pub fn icon(self) -> &'static str {
+        match self {
+            Severity::Info => "ℹ",
+            Severity::Warning => "⚠",
+            Severity::Error => "❌",
+        }
+    }
+
+    // Помилки висять, доки користувач їх не закриє
+    fn lifetime(self) -> Option<Duration> {
+        match self {
+            Severity::Info => Some(Duration::from_secs(3)),
+            Severity::Warning => Some(Duration::from_secs(6)),
+            Severity::Error => None,
+        }
+    }
+}
+
+/// Дія, яку можна повторити прямо зі сповіщення про помилку.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RetryAction {
+    Save,
+    ReloadProblems,
+    Delete(i32),
+    Restore(i32),
+    Purge(i32),
+    EmptyTrash,
+    OpenUrl(String),
+}
+
+pub struct Toast {
+    pub id: u64,
+    pub severity: Severity,
+    pub message: String,
+    pub details: Option<String>,
+    pub retry: Option<RetryAction>,
+    created: Instant,
+}
+
+impl Toast {
+    pub fn remaining(&self) -> Option<Duration> {
+        self.severity
+            .lifetime()
+            .map(|lifetime| lifetime.saturating_sub(self.created.elapsed()))
+    }
+}
+
+#[derive(Default)]
+pub struct Notifications {
+    toasts: VecDeque<Toast>,
+    next_id: u64,
+}
+
+impl Notifications {
+    pub fn push(&mut self, severity: Severity, message: String, details: Option<String>, retry: Option<RetryAction>) {
+        self.next_id += 1;
+        self.toasts.push_back(Toast {
+            id: self.next_id,
+            severity,
+            message,
+            details,
+            retry,
+            created: Instant::now(),
+        });
+
+        while self.toasts.len() > MAX_TOASTS {
+            self.toasts.pop_front();
+        }
+    }
+
+    pub fn dismiss(&mut self, id: u64) {
+        self.toasts.retain(|toast| toast.id != id);
+    }
+
+    /// Прибирає сповіщення, час яких минув, і повертає, коли зникне наступне.
+    pub fn expire(&mut self) -> Option<Duration> {
+        self.toasts.retain(|toast| toast.remaining() != Some(Duration::ZERO));
+        self.toasts.iter().filter_map(Toast::remaining).min()
+    }
+
+    pub fn toasts(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter()
+    }
+}
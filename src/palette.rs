@@ -0,0 +1,119 @@
+use crate::i18n::Language;
+use crate::keybindings::Command;
+use crate::locale::Locale;
+use crate::theme::ThemeChoice;
+
+/// Скільки найкращих збігів показувати у палітрі.
+pub const MAX_RESULTS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteAction {
+    Command(Command),
+    OpenSettings,
+    SetTheme(ThemeChoice),
+    SetLanguage(Language),
+    SetLocale(Locale),
+    OpenProblem(String),
+}
+
+pub struct PaletteItem {
+    pub label: String,
+    pub hint: String,
+    pub action: PaletteAction,
+}
+
+#[derive(Default)]
+pub struct Palette {
+    pub query: String,
+    pub selected: usize,
+}
+
+impl Palette {
+    /// Відбирає та сортує елементи за якістю збігу з запитом.
+    pub fn filter(&self, items: Vec<PaletteItem>) -> Vec<PaletteItem> {
+        let mut scored = items
+            .into_iter()
+            .filter_map(|item| fuzzy_score(&self.query, &item.label).map(|score| (score, item)))
+            .collect::<Vec<_>>();
+
+        // Стабільне сортування зберігає початковий порядок для однакових оцінок
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().take(MAX_RESULTS).map(|(_, item)| item).collect()
+    }
+}
+
+/// Нечіткий пошук: усі символи запиту мають зустрітися в тексті в тому ж порядку.
+/// Підряд розташовані символи та початки слів дають більшу оцінку.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let text = text.to_lowercase().chars().collect::<Vec<_>>();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for ch in query.chars().filter(|ch| !ch.is_whitespace()) {
+        let found = (position..text.len()).find(|&idx| text[idx] == ch)?;
+
+        score += 1;
+        if previous_match.is_some_and(|prev| prev + 1 == found) {
+            score += 5;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 3;
+        }
+        score -= (found - position) as i32 / 4;
+
+        previous_match = Some(found);
+        position = found + 1;
+    }
+
+    Some(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(label: &str) -> PaletteItem {
+        PaletteItem {
+            label: label.to_string(),
+            hint: String::new(),
+            action: PaletteAction::OpenSettings,
+        }
+    }
+
+    #[test]
+    fn matches_subsequences_case_insensitively() {
+        assert!(fuzzy_score("gen", "Generate problem").is_some());
+        assert!(fuzzy_score("GNR", "generate").is_some());
+        assert!(fuzzy_score("dark theme", "Theme: Dark").is_none());
+        assert_eq!(fuzzy_score("  ", "anything"), Some(0));
+    }
+
+    #[test]
+    fn rejects_missing_or_reordered_characters() {
+        assert_eq!(fuzzy_score("xyz", "Generate"), None);
+        assert_eq!(fuzzy_score("neg", "Generate"), None);
+        assert_eq!(fuzzy_score("generates", "Generate"), None);
+    }
+
+    #[test]
+    fn prefix_and_word_boundaries_rank_above_scattered_matches() {
+        let prefix = fuzzy_score("sav", "Save problem").unwrap();
+        let scattered = fuzzy_score("sav", "Show all versions").unwrap();
+        assert!(prefix > scattered);
+
+        let boundary = fuzzy_score("op", "Theme: open contrast").unwrap();
+        let inner = fuzzy_score("op", "Stop timer").unwrap();
+        assert!(boundary > inner);
+
+        let palette = Palette { query: "sav".to_string(), selected: 0 };
+        let results = palette.filter(vec![item("Show all versions"), item("Copy"), item("Save problem")]);
+        let labels = results.iter().map(|item| item.label.as_str()).collect::<Vec<_>>();
+        assert_eq!(labels, vec!["Save problem", "Show all versions"]);
+    }
+}
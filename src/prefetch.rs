@@ -0,0 +1,202 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+
+use crate::fetcher::{CancelToken, Fetcher, RequestId, TitleResponse};
+use crate::judge::Judge;
+use crate::locale::Locale;
+use crate::title::ProblemMeta;
+
+pub const DEFAULT_POOL_SIZE: usize = 5;
+pub const MAX_POOL_SIZE: usize = 20;
+
+// Після кожного збою (окрім відсутньої задачі) поповнення призупиняється на дедалі довший час, щоб не засипати сервер запитами
+const MIN_BACKOFF: Duration = Duration::from_secs(2);
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Задача, для якої вже перевірено, що сторінка існує, та завантажено назву.
+#[derive(Debug, Clone)]
+pub struct PrefetchedProblem {
+    pub judge: Judge,
+    pub problem_id: String,
+    pub url: String,
+    pub title: String,
+    pub meta: ProblemMeta,
+}
+
+struct InFlight {
+    judge: Judge,
+    problem_id: String,
+    url: String,
+    cancel: CancelToken,
+}
+
+/// Фонова черга готових випадкових задач, щоб Generate не чекав на мережу.
+pub struct PrefetchPool {
+    size: usize,
+    locale: Locale,
+    judges: Vec<Judge>,
+    ready: VecDeque<PrefetchedProblem>,
+    in_flight: HashMap<RequestId, InFlight>,
+    paused_until: Option<Instant>,
+    backoff: Duration,
+}
+
+impl PrefetchPool {
+    pub fn new(size: usize, locale: Locale, judges: Vec<Judge>) -> Self {
+        Self {
+            size: size.min(MAX_POOL_SIZE),
+            locale,
+            judges,
+            ready: VecDeque::new(),
+            in_flight: HashMap::new(),
+            paused_until: None,
+            backoff: MIN_BACKOFF,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn ready_count(&self) -> usize {
+        self.ready.len()
+    }
+
+    pub fn set_size(&mut self, size: usize) {
+        self.size = size.min(MAX_POOL_SIZE);
+        self.ready.truncate(self.size);
+
+        let excess = (self.ready.len() + self.in_flight.len()).saturating_sub(self.size);
+        let cancelled = self.in_flight.keys().copied().take(excess).collect::<Vec<_>>();
+        for request_id in cancelled {
+            if let Some(request) = self.in_flight.remove(&request_id) {
+                request.cancel.cancel();
+            }
+        }
+    }
+
+    /// Задачі, завантажені для іншої локалі, вже не підходять — пул наповнюється заново.
+    pub fn set_locale(&mut self, locale: Locale) {
+        if self.locale == locale {
+            return;
+        }
+        self.locale = locale;
+        self.clear();
+    }
+
+    pub fn set_judges(&mut self, judges: Vec<Judge>) {
+        if self.judges == judges {
+            return;
+        }
+        self.judges = judges;
+        self.clear();
+    }
+
+    pub fn clear(&mut self) {
+        self.ready.clear();
+        for (_, request) in self.in_flight.drain() {
+            request.cancel.cancel();
+        }
+        self.paused_until = None;
+        self.backoff = MIN_BACKOFF;
+    }
+
+    pub fn pop(&mut self) -> Option<PrefetchedProblem> {
+        self.ready.pop_front()
+    }
+
+    /// Готова задача з відомою складністю, найближчою до `target`; якщо таких немає — найстаріша.
+    pub fn pop_nearest(&mut self, target: u32) -> Option<PrefetchedProblem> {
+        let nearest = self.ready
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, problem)| Some((idx, problem.meta.difficulty?.abs_diff(target))))
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(idx, _)| idx);
+
+        match nearest {
+            Some(idx) => self.ready.remove(idx),
+            None => self.ready.pop_front(),
+        }
+    }
+
+    pub fn owns(&self, request_id: RequestId) -> bool {
+        self.in_flight.contains_key(&request_id)
+    }
+
+    /// Запускає стільки запитів, скільки бракує до повного пулу.
+    pub fn refill(
+        &mut self,
+        fetcher: &Fetcher,
+        next_request_id: &mut RequestId,
+        exclude: Option<(Judge, &str)>,
+        build_url: impl Fn(Judge, &str, Locale) -> String,
+    ) {
+        if self.judges.is_empty() {
+            return;
+        }
+
+        if self.paused_until.is_some_and(|until| Instant::now() < until) {
+            return;
+        }
+        self.paused_until = None;
+
+        let mut rng = rand::rng();
+        // На вузькому діапазоні вільних id може й не залишитися
+        let mut attempts = self.size * 4;
+        while self.ready.len() + self.in_flight.len() < self.size && attempts > 0 {
+            attempts -= 1;
+
+            let judge = self.judges[rng.random_range(0..self.judges.len())];
+            let problem_id = judge.random_id(&mut rng);
+            if exclude == Some((judge, problem_id.as_str())) || self.contains(judge, &problem_id) {
+                continue;
+            }
+
+            *next_request_id += 1;
+            let url = build_url(judge, &problem_id, self.locale);
+            let cancel = fetcher.fetch_title(*next_request_id, judge, problem_id.clone(), url.clone(), self.locale);
+            self.in_flight.insert(*next_request_id, InFlight { judge, problem_id, url, cancel });
+        }
+    }
+
+    /// Обробляє відповідь на один із запитів пулу.
+    pub fn receive(&mut self, response: TitleResponse) {
+        let Some(request) = self.in_flight.remove(&response.request_id) else {
+            return;
+        };
+
+        match response.result {
+            Ok(title) => {
+                self.backoff = MIN_BACKOFF;
+                self.ready.push_back(PrefetchedProblem {
+                    judge: request.judge,
+                    problem_id: request.problem_id,
+                    url: request.url,
+                    title,
+                    meta: response.meta,
+                });
+            }
+            // У суддів із розрідженими id відсутня задача — звичайна справа: її просто відкидаємо
+            Err(e) if e.is_missing() => {}
+            // Без мережі чи коли сайт відмовляє наступне поповнення чекає; скидає паузу лише успіх.
+            // Решта помилок (вхід, зламаний запис) повторилася б для кожного id, тож теж чекає
+            Err(_) => {
+                self.paused_until = Some(Instant::now() + self.backoff);
+                self.backoff = (self.backoff * 2).min(MAX_BACKOFF);
+            }
+        }
+    }
+
+    /// Коли варто ще раз спробувати поповнити пул після паузи.
+    pub fn resume_in(&self) -> Option<Duration> {
+        self.paused_until.map(|until| until.saturating_duration_since(Instant::now()))
+    }
+
+    fn contains(&self, judge: Judge, problem_id: &str) -> bool {
+        self.ready.iter().any(|problem| problem.judge == judge && problem.problem_id == problem_id)
+            || self.in_flight.values().any(|request| request.judge == judge && request.problem_id == problem_id)
+    }
+}
@@ -0,0 +1,110 @@
+pub const INITIAL_RATING: f64 = 1500.0;
+
+// Наскільки задачі мають бути складнішими за поточний рейтинг
+const STRETCH: f64 = 100.0;
+const USER_K: f64 = 32.0;
+const PROBLEM_K: f64 = 16.0;
+
+// Розв'язок за пів години зараховується повністю, довший — дедалі менше, але не нижче 0.6
+const FULL_CREDIT_MINUTES: f64 = 30.0;
+const MIN_CREDIT_MINUTES: f64 = 120.0;
+const MIN_SOLVED_SCORE: f64 = 0.6;
+
+/// Результат спроби розв'язати задачу.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Outcome {
+    pub solved: bool,
+    pub minutes: Option<u32>,
+}
+
+impl Outcome {
+    fn score(self) -> f64 {
+        if !self.solved {
+            return 0.0;
+        }
+
+        let Some(minutes) = self.minutes else {
+            return 1.0;
+        };
+        let slowness = ((minutes as f64 - FULL_CREDIT_MINUTES) / (MIN_CREDIT_MINUTES - FULL_CREDIT_MINUTES)).clamp(0.0, 1.0);
+        1.0 - slowness * (1.0 - MIN_SOLVED_SCORE)
+    }
+}
+
+/// Ймовірність розв'язати задачу складності `difficulty` з рейтингом `rating` за формулою Ело.
+pub fn expected(rating: f64, difficulty: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((difficulty - rating) / 400.0))
+}
+
+/// Новий рейтинг користувача та нова оцінка складності задачі.
+pub fn update(rating: f64, difficulty: f64, outcome: Outcome) -> (f64, f64) {
+    let surprise = outcome.score() - expected(rating, difficulty);
+    (rating + USER_K * surprise, difficulty - PROBLEM_K * surprise)
+}
+
+/// Складність, на яку цілиться генерація: трохи вище поточного рівня.
+pub fn target(rating: f64) -> u32 {
+    (rating + STRETCH).round().max(0.0) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOLVED: Outcome = Outcome { solved: true, minutes: None };
+    const FAILED: Outcome = Outcome { solved: false, minutes: None };
+
+    #[test]
+    fn equal_opponents_move_by_half_k() {
+        let (rating, difficulty) = update(1500.0, 1500.0, SOLVED);
+        assert_eq!(rating, 1516.0);
+        assert_eq!(difficulty, 1492.0);
+
+        let (rating, difficulty) = update(1500.0, 1500.0, FAILED);
+        assert_eq!(rating, 1484.0);
+        assert_eq!(difficulty, 1508.0);
+    }
+
+    #[test]
+    fn hard_problems_reward_more() {
+        let (easy, _) = update(1500.0, 1200.0, SOLVED);
+        let (hard, _) = update(1500.0, 1800.0, SOLVED);
+        assert!(hard - 1500.0 > easy - 1500.0);
+    }
+
+    #[test]
+    fn extreme_gaps_stay_within_k() {
+        // Розрив у 2500 пунктів: результат майже напевно відомий наперед
+        assert!(expected(3000.0, 500.0) > 0.999);
+        assert!(expected(500.0, 3000.0) < 0.001);
+
+        let (rating, difficulty) = update(3000.0, 500.0, SOLVED);
+        assert!(rating - 3000.0 < 0.1 && rating >= 3000.0);
+        assert!(500.0 - difficulty < 0.1);
+
+        // Несподіваний результат зсуває рейтинг майже на весь K, але не більше
+        let (rating, difficulty) = update(3000.0, 500.0, FAILED);
+        assert!(3000.0 - rating > USER_K - 0.1 && 3000.0 - rating <= USER_K);
+        assert!(difficulty - 500.0 <= PROBLEM_K);
+
+        let (rating, _) = update(500.0, 3000.0, SOLVED);
+        assert!(rating - 500.0 > USER_K - 0.1 && rating - 500.0 <= USER_K);
+    }
+
+    #[test]
+    fn target_is_never_negative() {
+        assert_eq!(target(INITIAL_RATING), 1600);
+        assert_eq!(target(-400.0), 0);
+    }
+
+    #[test]
+    fn slow_solutions_earn_partial_credit() {
+        let quick = Outcome { solved: true, minutes: Some(20) };
+        let slow = Outcome { solved: true, minutes: Some(90) };
+        let very_slow = Outcome { solved: true, minutes: Some(600) };
+
+        assert_eq!(quick.score(), 1.0);
+        assert!(slow.score() < 1.0 && slow.score() > MIN_SOLVED_SCORE);
+        assert_eq!(very_slow.score(), MIN_SOLVED_SCORE);
+    }
+}
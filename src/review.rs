@@ -0,0 +1,187 @@
+use chrono::{Days, NaiveDate};
+
+use crate::db::Review;
+
+pub const INITIAL_EASE: f64 = 2.5;
+const MIN_EASE: f64 = 1.3;
+
+// Другий успішний перегляд — через тиждень без одного дня, як в оригінальному SM-2
+const FIRST_INTERVAL: i32 = 1;
+const SECOND_INTERVAL: i32 = 6;
+
+/// Наскільки легко вдалося розв'язати задачу повторно.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Grade {
+    Again,
+    Hard,
+    Good,
+    Easy,
+}
+
+impl Grade {
+    pub const ALL: [Grade; 4] = [Grade::Again, Grade::Hard, Grade::Good, Grade::Easy];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Grade::Again => "again",
+            Grade::Hard => "hard",
+            Grade::Good => "good",
+            Grade::Easy => "easy",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|grade| grade.code() == code)
+    }
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Grade::Again => "review.grade.again",
+            Grade::Hard => "review.grade.hard",
+            Grade::Good => "review.grade.good",
+            Grade::Easy => "review.grade.easy",
+        }
+    }
+
+    // Оцінка якості відповіді за шкалою SM-2 від 0 до 5; нижче 3 — не згадав
+    fn quality(self) -> f64 {
+        match self {
+            Grade::Again => 1.0,
+            Grade::Hard => 3.0,
+            Grade::Good => 4.0,
+            Grade::Easy => 5.0,
+        }
+    }
+}
+
+/// Розклад щойно збереженої задачі: перше повторення наступного дня.
+pub fn initial(problem: i32, today: NaiveDate) -> Review {
+    Review {
+        problem,
+        ease: INITIAL_EASE,
+        interval_days: 0,
+        repetitions: 0,
+        due: today + Days::new(FIRST_INTERVAL as u64),
+        last_grade: None,
+        reviewed_at: None,
+    }
+}
+
+/// Новий розклад після повторення з оцінкою `grade`.
+pub fn schedule(review: &Review, grade: Grade, today: NaiveDate) -> Review {
+    let quality = grade.quality();
+    let (repetitions, interval_days) = if quality < 3.0 {
+        (0, FIRST_INTERVAL)
+    }
+    else {
+        let interval = match review.repetitions {
+            0 => FIRST_INTERVAL,
+            1 => SECOND_INTERVAL,
+            _ => (review.interval_days as f64 * review.ease).round() as i32,
+        };
+        (review.repetitions + 1, interval)
+    };
+
+    let penalty = 5.0 - quality;
+    let ease = (review.ease + 0.1 - penalty * (0.08 + penalty * 0.02)).max(MIN_EASE);
+
+    Review {
+        problem: review.problem,
+        ease,
+        interval_days,
+        repetitions,
+        due: today + Days::new(interval_days as u64),
+        last_grade: Some(grade.code().to_string()),
+        reviewed_at: Some(chrono::Utc::now().naive_utc()),
+    }
+}
+
+pub fn is_due(review: &Review, today: NaiveDate) -> bool {
+    review.due <= today
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn intervals_grow_after_successful_reviews() {
+        let today = date(2025, 4, 1);
+        let mut review = initial(1, today);
+        assert_eq!(review.due, date(2025, 4, 2));
+
+        let mut intervals = Vec::new();
+        for _ in 0..4 {
+            review = schedule(&review, Grade::Good, review.due);
+            intervals.push(review.interval_days);
+        }
+        assert_eq!(intervals, vec![1, 6, 15, 38]);
+        assert_eq!(review.ease, INITIAL_EASE);
+    }
+
+    #[test]
+    fn failure_resets_progress_and_lowers_ease() {
+        let today = date(2025, 4, 1);
+        let mut review = initial(1, today);
+        review = schedule(&review, Grade::Easy, today);
+        review = schedule(&review, Grade::Easy, today);
+        assert_eq!(review.repetitions, 2);
+
+        let failed = schedule(&review, Grade::Again, today);
+        assert_eq!(failed.repetitions, 0);
+        assert_eq!(failed.due, date(2025, 4, 2));
+        assert!(failed.ease < review.ease);
+        assert_eq!(failed.last_grade.as_deref(), Some("again"));
+    }
+
+    #[test]
+    fn lapse_restarts_intervals_at_minimum_ease() {
+        let today = date(2025, 4, 1);
+        let mature = Review { ease: 1.4, interval_days: 20, repetitions: 5, ..initial(1, today) };
+
+        // Провал забирає 0.54 легкості, але не нижче мінімуму, і повертає до першого інтервалу
+        let lapsed = schedule(&mature, Grade::Again, today);
+        assert_eq!(lapsed.ease, MIN_EASE);
+        assert_eq!((lapsed.repetitions, lapsed.interval_days), (0, FIRST_INTERVAL));
+
+        // Далі розклад будується заново, уже з мінімальною легкістю: 1, 6, 6 × 1.3
+        let mut review = lapsed;
+        let mut intervals = Vec::new();
+        for _ in 0..3 {
+            review = schedule(&review, Grade::Good, review.due);
+            intervals.push(review.interval_days);
+        }
+        assert_eq!(intervals, vec![1, 6, 8]);
+        assert_eq!(review.due, date(2025, 4, 17));
+    }
+
+    #[test]
+    fn hard_keeps_progress_but_lowers_ease() {
+        let today = date(2025, 4, 1);
+        let review = Review { interval_days: 6, repetitions: 2, ..initial(1, today) };
+
+        let hard = schedule(&review, Grade::Hard, today);
+        assert_eq!((hard.repetitions, hard.interval_days), (3, 15));
+        assert!((hard.ease - 2.36).abs() < 1e-9);
+
+        let easy = schedule(&review, Grade::Easy, today);
+        assert_eq!(easy.interval_days, hard.interval_days);
+        assert!(easy.ease > INITIAL_EASE);
+    }
+
+    #[test]
+    fn ease_never_drops_below_minimum() {
+        let today = date(2025, 4, 1);
+        let mut review = initial(1, today);
+        for _ in 0..20 {
+            review = schedule(&review, Grade::Again, today);
+        }
+        assert_eq!(review.ease, MIN_EASE);
+        assert!(is_due(&review, date(2025, 4, 2)));
+        assert!(!is_due(&review, today));
+    }
+}
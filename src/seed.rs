@@ -0,0 +1,141 @@
+use rand::rngs::ChaCha8Rng;
+use rand::{Rng, SeedableRng};
+
+use crate::judge::{self, Judge};
+
+const RADIX: u32 = 36;
+
+/// Зерно разом із фільтрами, потрібними, щоб відтворити ту саму послідовність задач:
+/// `<зерно>-<маска суддів>` у системі числення з основою 36, наприклад `2kzq1v8w0xq3c-f`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShareCode {
+    pub seed: u64,
+    pub judges: Vec<Judge>,
+}
+
+impl ShareCode {
+    pub fn random(judges: &[Judge]) -> Self {
+        Self {
+            seed: rand::rng().random(),
+            judges: judges.to_vec(),
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let mask = Judge::ALL
+            .iter()
+            .enumerate()
+            .filter(|(_, judge)| self.judges.contains(judge))
+            .fold(0u64, |mask, (idx, _)| mask | 1 << idx);
+
+        format!("{}-{}", to_base36(self.seed), to_base36(mask))
+    }
+
+    /// Приймає повний код або саме зерно; без маски беруться `default_judges`.
+    pub fn parse(text: &str, default_judges: &[Judge]) -> Option<Self> {
+        let text = text.trim();
+        let (seed, mask) = match text.split_once('-') {
+            Some((seed, mask)) => (seed, Some(mask)),
+            None => (text, None),
+        };
+
+        let seed = u64::from_str_radix(seed, RADIX).ok()?;
+        let judges = match mask {
+            Some(mask) => {
+                let mask = u64::from_str_radix(mask, RADIX).ok()?;
+                if mask >> Judge::ALL.len() != 0 {
+                    return None;
+                }
+                Judge::ALL
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(idx, _)| mask & 1 << idx != 0)
+                    .map(|(_, judge)| judge)
+                    .collect()
+            }
+            None => default_judges.to_vec(),
+        };
+
+        (!judges.is_empty()).then_some(Self { seed, judges })
+    }
+}
+
+/// Послідовність задач, що однаково розгортається з того самого коду на будь-якій машині.
+pub struct SeededRun {
+    code: ShareCode,
+    rng: ChaCha8Rng,
+    position: u32,
+}
+
+impl SeededRun {
+    pub fn new(code: ShareCode) -> Self {
+        Self {
+            rng: ChaCha8Rng::seed_from_u64(code.seed),
+            code,
+            position: 0,
+        }
+    }
+
+    pub fn code(&self) -> &ShareCode {
+        &self.code
+    }
+
+    /// Номер останньої виданої задачі, починаючи з 1.
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    pub fn next_problem(&mut self) -> (Judge, String) {
+        self.position += 1;
+        judge::random_problem(&mut self.rng, &self.code.judges)
+    }
+}
+
+fn to_base36(mut value: u64) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit((value % RADIX as u64) as u32, RADIX).unwrap_or('0'));
+        value /= RADIX as u64;
+        if value == 0 {
+            break;
+        }
+    }
+    digits.into_iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn share_code_round_trips() {
+        let code = ShareCode { seed: u64::MAX, judges: vec![Judge::Codeforces, Judge::Timus] };
+        let text = code.encode();
+
+        assert_eq!(ShareCode::parse(&text, &[]), Some(code.clone()));
+        assert_eq!(ShareCode::parse(&format!(" {} ", text.to_uppercase()), &[]), Some(code));
+    }
+
+    #[test]
+    fn bare_seed_uses_current_judges() {
+        assert_eq!(
+            ShareCode::parse("zz", &[Judge::AtCoder]),
+            Some(ShareCode { seed: 36 * 36 - 1, judges: vec![Judge::AtCoder] })
+        );
+        assert_eq!(ShareCode::parse("zz-0", &[Judge::AtCoder]), None);
+        assert_eq!(ShareCode::parse("zz-10", &[]), None);
+        assert_eq!(ShareCode::parse("not a seed", &[Judge::Eolymp]), None);
+    }
+
+    #[test]
+    fn same_code_gives_same_sequence() {
+        let code = ShareCode::random(&Judge::ALL);
+        let mut first = SeededRun::new(code.clone());
+        let mut second = SeededRun::new(ShareCode::parse(&code.encode(), &[]).unwrap());
+
+        for _ in 0..20 {
+            assert_eq!(first.next_problem(), second.next_problem());
+        }
+        assert_eq!(first.position(), 20);
+    }
+}
@@ -0,0 +1,110 @@
+use chrono::{Datelike, Days, NaiveDate};
+
+use crate::db::{BucketCount, DayCount, SolveSummary, TagCount};
+
+pub const DAYS_SHOWN: u32 = 30;
+pub const WEEKS_SHOWN: u32 = 12;
+pub const DIFFICULTY_BUCKET: i32 = 200;
+pub const TAGS_SHOWN: i64 = 10;
+
+/// Усе, що показує вікно статистики; дні й тижні без розв'язків заповнені нулями.
+#[derive(Debug, Clone, Default)]
+pub struct Dashboard {
+    pub per_day: Vec<(NaiveDate, i64)>,
+    pub per_week: Vec<(NaiveDate, i64)>,
+    pub difficulty: Vec<(i32, i64)>,
+    pub tags: Vec<TagCount>,
+    pub summary: SolveSummary,
+}
+
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Days::new(date.weekday().num_days_from_monday() as u64)
+}
+
+/// Останні `count` кроків по `step` днів до `last` включно, від найдавнішого.
+fn fill(counts: &[DayCount], last: NaiveDate, count: u32, step: u64) -> Vec<(NaiveDate, i64)> {
+    (0..count as u64)
+        .rev()
+        .filter_map(|idx| last.checked_sub_days(Days::new(idx * step)))
+        .map(|day| {
+            let solved = counts.iter().find(|entry| entry.day == day).map_or(0, |entry| entry.count);
+            (day, solved)
+        })
+        .collect()
+}
+
+pub fn fill_days(counts: &[DayCount], today: NaiveDate) -> Vec<(NaiveDate, i64)> {
+    fill(counts, today, DAYS_SHOWN, 1)
+}
+
+pub fn fill_weeks(counts: &[DayCount], today: NaiveDate) -> Vec<(NaiveDate, i64)> {
+    fill(counts, week_start(today), WEEKS_SHOWN, 7)
+}
+
+/// Кошики складності від найменшого до найбільшого без пропусків між ними.
+pub fn fill_buckets(counts: &[BucketCount]) -> Vec<(i32, i64)> {
+    let (Some(first), Some(last)) = (counts.first(), counts.last()) else {
+        return Vec::new();
+    };
+
+    (first.bucket..=last.bucket)
+        .step_by(DIFFICULTY_BUCKET as usize)
+        .map(|bucket| {
+            let solved = counts.iter().find(|entry| entry.bucket == bucket).map_or(0, |entry| entry.count);
+            (bucket, solved)
+        })
+        .collect()
+}
+
+/// Частка розв'язаних серед збережених; `None`, поки нічого не збережено.
+pub fn solved_ratio(summary: &SolveSummary) -> Option<f32> {
+    (summary.saved > 0).then(|| summary.saved_solved as f32 / summary.saved as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn missing_days_and_weeks_are_zero() {
+        let counts = vec![
+            DayCount { day: date(2025, 3, 10), count: 2 },
+            DayCount { day: date(2025, 3, 14), count: 1 },
+        ];
+
+        let days = fill_days(&counts, date(2025, 3, 14));
+        assert_eq!(days.len(), DAYS_SHOWN as usize);
+        assert_eq!(days.last(), Some(&(date(2025, 3, 14), 1)));
+        assert_eq!(days.iter().map(|(_, count)| count).sum::<i64>(), 3);
+
+        // 14 березня 2025 — п'ятниця, тиждень почався 10-го
+        let weeks = fill_weeks(&counts, date(2025, 3, 14));
+        assert_eq!(weeks.len(), WEEKS_SHOWN as usize);
+        assert_eq!(weeks.last(), Some(&(date(2025, 3, 10), 2)));
+        assert_eq!(weeks[weeks.len() - 2].0, date(2025, 3, 3));
+    }
+
+    #[test]
+    fn difficulty_buckets_have_no_gaps() {
+        let counts = vec![
+            BucketCount { bucket: 800, count: 3 },
+            BucketCount { bucket: 1400, count: 1 },
+        ];
+        assert_eq!(fill_buckets(&counts), vec![(800, 3), (1000, 0), (1200, 0), (1400, 1)]);
+        assert!(fill_buckets(&[]).is_empty());
+    }
+
+    #[test]
+    fn ratio_needs_saved_problems() {
+        let mut summary = SolveSummary::default();
+        assert_eq!(solved_ratio(&summary), None);
+
+        summary.saved = 4;
+        summary.saved_solved = 1;
+        assert_eq!(solved_ratio(&summary), Some(0.25));
+    }
+}
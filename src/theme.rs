@@ -0,0 +1,343 @@
+use std::fs;
+use std::path::Path;
+
+use eframe::egui::{self, Color32};
+use serde::{Deserialize, Serialize};
+
+// Власні теми шукаються у файлах `themes/*.toml` поруч із застосунком
+const THEMES_DIR: &str = "themes";
+
+/// Набір семантичних кольорів, якими малюється інтерфейс.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    pub dark: bool,
+    #[serde(with = "hex_color")]
+    pub background: Color32,
+    #[serde(with = "hex_color")]
+    pub text: Color32,
+    #[serde(with = "hex_color")]
+    pub text_secondary: Color32,
+    #[serde(with = "hex_color")]
+    pub text_muted: Color32,
+    #[serde(with = "hex_color")]
+    pub text_faint: Color32,
+    #[serde(with = "hex_color")]
+    pub placeholder: Color32,
+    #[serde(with = "hex_color")]
+    pub accent: Color32,
+    #[serde(with = "hex_color")]
+    pub problem_name: Color32,
+    #[serde(with = "hex_color")]
+    pub problem_url: Color32,
+    #[serde(with = "hex_color")]
+    pub link: Color32,
+    #[serde(with = "hex_color")]
+    pub loading: Color32,
+    #[serde(with = "hex_color")]
+    pub success: Color32,
+    #[serde(with = "hex_color")]
+    pub info: Color32,
+    #[serde(with = "hex_color")]
+    pub warning: Color32,
+    #[serde(with = "hex_color")]
+    pub error: Color32,
+    #[serde(with = "hex_color")]
+    pub button_text: Color32,
+    #[serde(with = "hex_color")]
+    pub generate_fill: Color32,
+    #[serde(with = "hex_color")]
+    pub generate_stroke: Color32,
+    #[serde(with = "hex_color")]
+    pub open_fill: Color32,
+    #[serde(with = "hex_color")]
+    pub open_stroke: Color32,
+    #[serde(with = "hex_color")]
+    pub copy_fill: Color32,
+    #[serde(with = "hex_color")]
+    pub copy_stroke: Color32,
+    #[serde(with = "hex_color")]
+    pub save_fill: Color32,
+    #[serde(with = "hex_color")]
+    pub save_stroke: Color32,
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".to_string(),
+            dark: true,
+            background: Color32::from_rgb(15, 15, 15),
+            text: Color32::from_rgb(200, 200, 200),
+            text_secondary: Color32::from_rgb(150, 150, 150),
+            text_muted: Color32::from_rgb(100, 100, 100),
+            text_faint: Color32::from_rgb(80, 80, 80),
+            placeholder: Color32::DARK_GRAY,
+            accent: Color32::from_rgb(200, 150, 255),
+            problem_name: Color32::from_rgb(150, 200, 255),
+            problem_url: Color32::from_rgb(150, 200, 150),
+            link: Color32::from_rgb(100, 150, 200),
+            loading: Color32::YELLOW,
+            success: Color32::GREEN,
+            info: Color32::from_rgb(120, 180, 255),
+            warning: Color32::from_rgb(255, 190, 80),
+            error: Color32::from_rgb(255, 100, 100),
+            button_text: Color32::WHITE,
+            generate_fill: Color32::from_rgb(200, 100, 255),
+            generate_stroke: Color32::from_rgb(220, 150, 255),
+            open_fill: Color32::from_rgb(100, 200, 150),
+            open_stroke: Color32::from_rgb(150, 255, 200),
+            copy_fill: Color32::from_rgb(255, 180, 100),
+            copy_stroke: Color32::from_rgb(255, 220, 150),
+            save_fill: Color32::from_rgb(100, 100, 100),
+            save_stroke: Color32::from_rgb(255, 220, 150),
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            name: "light".to_string(),
+            dark: false,
+            background: Color32::from_rgb(245, 245, 245),
+            text: Color32::from_rgb(40, 40, 40),
+            text_secondary: Color32::from_rgb(90, 90, 90),
+            text_muted: Color32::from_rgb(120, 120, 120),
+            text_faint: Color32::from_rgb(150, 150, 150),
+            placeholder: Color32::from_rgb(160, 160, 160),
+            accent: Color32::from_rgb(130, 60, 200),
+            problem_name: Color32::from_rgb(30, 90, 170),
+            problem_url: Color32::from_rgb(40, 120, 60),
+            link: Color32::from_rgb(40, 90, 160),
+            loading: Color32::from_rgb(180, 130, 0),
+            success: Color32::from_rgb(0, 140, 60),
+            info: Color32::from_rgb(30, 100, 200),
+            warning: Color32::from_rgb(190, 110, 0),
+            error: Color32::from_rgb(200, 30, 30),
+            button_text: Color32::WHITE,
+            generate_fill: Color32::from_rgb(160, 70, 220),
+            generate_stroke: Color32::from_rgb(120, 40, 180),
+            open_fill: Color32::from_rgb(40, 150, 100),
+            open_stroke: Color32::from_rgb(20, 110, 70),
+            copy_fill: Color32::from_rgb(220, 130, 40),
+            copy_stroke: Color32::from_rgb(180, 100, 20),
+            save_fill: Color32::from_rgb(110, 110, 110),
+            save_stroke: Color32::from_rgb(80, 80, 80),
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "high_contrast".to_string(),
+            dark: true,
+            background: Color32::BLACK,
+            text: Color32::WHITE,
+            text_secondary: Color32::from_rgb(230, 230, 230),
+            text_muted: Color32::from_rgb(200, 200, 200),
+            text_faint: Color32::from_rgb(180, 180, 180),
+            placeholder: Color32::from_rgb(170, 170, 170),
+            accent: Color32::from_rgb(255, 128, 255),
+            problem_name: Color32::from_rgb(0, 255, 255),
+            problem_url: Color32::from_rgb(0, 255, 0),
+            link: Color32::from_rgb(100, 200, 255),
+            loading: Color32::YELLOW,
+            success: Color32::from_rgb(0, 255, 0),
+            info: Color32::from_rgb(0, 255, 255),
+            warning: Color32::YELLOW,
+            error: Color32::from_rgb(255, 60, 60),
+            button_text: Color32::BLACK,
+            generate_fill: Color32::from_rgb(255, 128, 255),
+            generate_stroke: Color32::WHITE,
+            open_fill: Color32::from_rgb(0, 255, 128),
+            open_stroke: Color32::WHITE,
+            copy_fill: Color32::from_rgb(255, 200, 0),
+            copy_stroke: Color32::WHITE,
+            save_fill: Color32::from_rgb(200, 200, 200),
+            save_stroke: Color32::WHITE,
+        }
+    }
+
+    pub fn visuals(&self) -> egui::Visuals {
+        let mut visuals = if self.dark {
+            egui::Visuals::dark()
+        } else {
+            egui::Visuals::light()
+        };
+
+        visuals.panel_fill = self.background;
+        visuals
+    }
+
+    pub fn apply(&self, ctx: &egui::Context) {
+        let theme = if self.dark {
+            egui::Theme::Dark
+        } else {
+            egui::Theme::Light
+        };
+
+        ctx.set_theme(theme);
+        ctx.set_visuals_of(theme, self.visuals());
+    }
+
+    // Файл теми задає лише ті кольори, які відрізняються від базової теми
+    fn from_file(path: &Path) -> Result<Self, String> {
+        #[derive(Deserialize)]
+        struct ThemeFile {
+            name: String,
+            #[serde(default)]
+            base: Option<String>,
+            dark: Option<bool>,
+            #[serde(default)]
+            colors: toml::Table,
+        }
+
+        let contents = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let file: ThemeFile = toml::from_str(&contents).map_err(|e| e.to_string())?;
+
+        let base = match file.base.as_deref() {
+            Some("light") => Self::light(),
+            Some("high_contrast") => Self::high_contrast(),
+            _ => Self::dark(),
+        };
+
+        let mut table = toml::Table::try_from(&base).map_err(|e| e.to_string())?;
+        for (key, value) in file.colors {
+            if !table.contains_key(&key) || key == "name" || key == "dark" {
+                return Err(format!("невідомий колір `{}`", key));
+            }
+            table.insert(key, value);
+        }
+        table.insert("name".to_string(), toml::Value::String(file.name));
+        table.insert("dark".to_string(), toml::Value::Boolean(file.dark.unwrap_or(base.dark)));
+
+        toml::Value::Table(table).try_into().map_err(|e| e.to_string())
+    }
+}
+
+/// Вибір теми користувачем; зберігається в налаштуваннях як рядок.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ThemeChoice {
+    #[default]
+    System,
+    Dark,
+    Light,
+    HighContrast,
+    Custom(String),
+}
+
+impl ThemeChoice {
+    pub const BUILTIN: [ThemeChoice; 4] = [
+        ThemeChoice::System,
+        ThemeChoice::Dark,
+        ThemeChoice::Light,
+        ThemeChoice::HighContrast,
+    ];
+
+    pub fn code(&self) -> String {
+        match self {
+            ThemeChoice::System => "system".to_string(),
+            ThemeChoice::Dark => "dark".to_string(),
+            ThemeChoice::Light => "light".to_string(),
+            ThemeChoice::HighContrast => "high_contrast".to_string(),
+            ThemeChoice::Custom(name) => format!("custom:{}", name),
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "system" => Some(ThemeChoice::System),
+            "dark" => Some(ThemeChoice::Dark),
+            "light" => Some(ThemeChoice::Light),
+            "high_contrast" => Some(ThemeChoice::HighContrast),
+            _ => code
+                .strip_prefix("custom:")
+                .map(|name| ThemeChoice::Custom(name.to_string())),
+        }
+    }
+
+    /// Ключ перекладу для вбудованих тем; власні теми показуються під своєю назвою.
+    pub fn label_key(&self) -> Option<&'static str> {
+        match self {
+            ThemeChoice::System => Some("theme.system"),
+            ThemeChoice::Dark => Some("theme.dark"),
+            ThemeChoice::Light => Some("theme.light"),
+            ThemeChoice::HighContrast => Some("theme.high_contrast"),
+            ThemeChoice::Custom(_) => None,
+        }
+    }
+}
+
+pub struct Themes {
+    custom: Vec<Theme>,
+}
+
+impl Themes {
+    /// Завантажує власні теми; файли, які не вдалося прочитати, повертаються як помилки.
+    pub fn load() -> (Self, Vec<String>) {
+        let mut custom = Vec::new();
+        let mut errors = Vec::new();
+
+        if let Ok(entries) = fs::read_dir(THEMES_DIR) {
+            let mut paths = entries
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+                .collect::<Vec<_>>();
+            paths.sort();
+
+            for path in paths {
+                match Theme::from_file(&path) {
+                    Ok(theme) => custom.push(theme),
+                    Err(e) => errors.push(format!("{}: {}", path.display(), e)),
+                }
+            }
+        }
+
+        (Self { custom }, errors)
+    }
+
+    pub fn custom(&self) -> &[Theme] {
+        &self.custom
+    }
+
+    pub fn resolve(&self, choice: &ThemeChoice, system: Option<egui::Theme>) -> Theme {
+        match choice {
+            ThemeChoice::System => match system {
+                Some(egui::Theme::Light) => Theme::light(),
+                _ => Theme::dark(),
+            },
+            ThemeChoice::Dark => Theme::dark(),
+            ThemeChoice::Light => Theme::light(),
+            ThemeChoice::HighContrast => Theme::high_contrast(),
+            ThemeChoice::Custom(name) => self.custom
+                .iter()
+                .find(|theme| &theme.name == name)
+                .cloned()
+                .unwrap_or_else(Theme::dark),
+        }
+    }
+}
+
+mod hex_color {
+    use eframe::egui::Color32;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Color32, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("#{:02x}{:02x}{:02x}", color.r(), color.g(), color.b()))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color32, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        let hex = value.trim_start_matches('#');
+
+        if hex.len() != 6 {
+            return Err(serde::de::Error::custom(format!("очікується колір у форматі #rrggbb, отримано `{}`", value)));
+        }
+
+        let channel = |range: std::ops::Range<usize>| {
+            hex.get(range)
+                .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+                .ok_or_else(|| serde::de::Error::custom(format!("некоректний колір `{}`", value)))
+        };
+
+        Ok(Color32::from_rgb(channel(0..2)?, channel(2..4)?, channel(4..6)?))
+    }
+}
@@ -0,0 +1,292 @@
+use scraper::{Html, Selector};
+
+use crate::fetcher::FetchError;
+use crate::judge::Judge;
+
+// Назви сайту, які eolymp дописує до заголовка сторінки
+const SITE_NAMES: &[&str] = &["eolymp", "eolymp.com", "www.eolymp.com"];
+const SEPARATORS: &[&str] = &[" - ", " – ", " — ", " | ", " · ", ": "];
+
+// Заголовки сторінок-заглушок; порівнюються з усім заголовком, а не з частиною,
+// щоб задача на кшталт "Error correction" не вважалася помилкою
+const ERROR_TITLES: &[&str] = &[
+    "404",
+    "not found",
+    "page not found",
+    "this page could not be found",
+    "404: this page could not be found",
+    "something went wrong",
+    "internal server error",
+    "service unavailable",
+    "error",
+    "сторінку не знайдено",
+    "сторінка не знайдена",
+    "щось пішло не так",
+    "страница не найдена",
+    "что-то пошло не так",
+];
+const LOGIN_TITLES: &[&str] = &["sign in", "log in", "login", "вхід", "увійти", "вход", "войти"];
+
+/// Теми та складність задачі, якщо суддя їх публікує на сторінці.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProblemMeta {
+    pub tags: Vec<String>,
+    pub difficulty: Option<u32>,
+}
+
+/// Дістає назву задачі зі сторінки судді.
+pub fn extract_title(judge: Judge, html: &str) -> Result<String, FetchError> {
+    let document = Html::parse_document(html);
+
+    // Інші судді при відсутній задачі часто перенаправляють на загальну сторінку,
+    // тому назву беремо лише з їхнього характерного заголовка умови
+    let heading = match judge {
+        Judge::Eolymp => return extract_eolymp_title(&document),
+        Judge::Codeforces => select_text(&document, ".problem-statement .header .title"),
+        Judge::AtCoder => select_first_text(&document, "span.h2"),
+        Judge::Timus => select_text(&document, "h2.problem_title"),
+    };
+
+    let title = heading
+        .map(|heading| strip_index(&normalize_whitespace(&heading)))
+        .unwrap_or_default();
+    if title.is_empty() {
+        return Err(FetchError::NoTitle);
+    }
+    Ok(title)
+}
+
+/// Поки що лише Codeforces показує теми та рейтинг задачі (тег `*1600`) у бічній панелі.
+pub fn extract_meta(judge: Judge, html: &str) -> ProblemMeta {
+    if judge != Judge::Codeforces {
+        return ProblemMeta::default();
+    }
+
+    let document = Html::parse_document(html);
+    let Ok(selector) = Selector::parse(".tag-box") else {
+        return ProblemMeta::default();
+    };
+
+    let mut meta = ProblemMeta::default();
+    for tag in document.select(&selector).map(|element| normalize_whitespace(&element.text().collect::<String>())) {
+        match tag.strip_prefix('*').and_then(|rating| rating.parse().ok()) {
+            Some(rating) => meta.difficulty = Some(rating),
+            None if !tag.is_empty() => meta.tags.push(tag.to_lowercase()),
+            None => {}
+        }
+    }
+    meta
+}
+
+// Спершу `og:title`, потім заголовок `h1`, і лише тоді `<title>`; назва сайту прибирається
+fn extract_eolymp_title(document: &Html) -> Result<String, FetchError> {
+    if has_password_field(document) {
+        return Err(FetchError::LoginRequired);
+    }
+
+    let candidates = [
+        select_attr(document, r#"meta[property="og:title"]"#, "content"),
+        select_text(document, "h1"),
+        select_text(document, "title"),
+    ];
+
+    for candidate in candidates.into_iter().flatten() {
+        let title = strip_site_name(&normalize_whitespace(&candidate));
+        if title.is_empty() {
+            continue;
+        }
+
+        let lower = title.to_lowercase();
+        if LOGIN_TITLES.contains(&lower.as_str()) {
+            return Err(FetchError::LoginRequired);
+        }
+        if ERROR_TITLES.contains(&lower.as_str()) || is_status_code(&lower) {
+            return Err(FetchError::ErrorPage(title));
+        }
+        return Ok(title);
+    }
+
+    Err(FetchError::NoTitle)
+}
+
+fn select_text(document: &Html, selector: &str) -> Option<String> {
+    let selector = Selector::parse(selector).ok()?;
+    document.select(&selector).next().map(|element| element.text().collect())
+}
+
+// Лише власний текст елемента, без вкладених посилань на кшталт "Editorial"
+fn select_first_text(document: &Html, selector: &str) -> Option<String> {
+    let selector = Selector::parse(selector).ok()?;
+    document.select(&selector).next()?.text().find(|text| !text.trim().is_empty()).map(str::to_string)
+}
+
+// "A. Watermelon", "A - Product", "1000. A+B Problem" → назва без номера задачі
+fn strip_index(title: &str) -> String {
+    for separator in [". ", " - "] {
+        if let Some((index, rest)) = title.split_once(separator)
+            && !index.is_empty()
+            && index.len() <= 5
+            && index.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return rest.trim().to_string();
+        }
+    }
+    title.to_string()
+}
+
+fn select_attr(document: &Html, selector: &str, attr: &str) -> Option<String> {
+    let selector = Selector::parse(selector).ok()?;
+    document.select(&selector).next()?.value().attr(attr).map(str::to_string)
+}
+
+fn has_password_field(document: &Html) -> bool {
+    Selector::parse(r#"input[type="password"]"#)
+        .is_ok_and(|selector| document.select(&selector).next().is_some())
+}
+
+pub fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Назва сайту може стояти як після назви задачі, так і перед нею
+fn strip_site_name(title: &str) -> String {
+    let is_site = |part: &str| SITE_NAMES.contains(&part.trim().to_lowercase().as_str());
+
+    if is_site(title) {
+        return String::new();
+    }
+
+    for separator in SEPARATORS {
+        if let Some((rest, site)) = title.rsplit_once(separator)
+            && is_site(site)
+        {
+            return rest.trim().to_string();
+        }
+        if let Some((site, rest)) = title.split_once(separator)
+            && is_site(site)
+        {
+            return rest.trim().to_string();
+        }
+    }
+
+    title.to_string()
+}
+
+// Заголовки на кшталт "404" чи "500: Internal Server Error", але не "500 miles"
+fn is_status_code(title: &str) -> bool {
+    let Some(code) = title.get(..3) else {
+        return false;
+    };
+    let rest = title[3..].trim_start();
+
+    code.chars().all(|c| c.is_ascii_digit())
+        && (code.starts_with('4') || code.starts_with('5'))
+        && (rest.is_empty() || rest.starts_with([':', '-', '|']))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> String {
+        let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+        std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+    }
+
+    #[test]
+    fn prefers_og_title() {
+        assert_eq!(extract_title(Judge::Eolymp, &fixture("problem_og_title.html")), Ok("Сума двох чисел".to_string()));
+    }
+
+    #[test]
+    fn falls_back_to_heading_when_title_is_only_branding() {
+        assert_eq!(
+            extract_title(Judge::Eolymp, &fixture("problem_heading_only.html")),
+            Ok("Shortest path in a grid".to_string())
+        );
+    }
+
+    #[test]
+    fn strips_site_suffix_and_normalizes_whitespace() {
+        assert_eq!(extract_title(Judge::Eolymp, &fixture("problem_title_suffix.html")), Ok("Простая задача".to_string()));
+    }
+
+    #[test]
+    fn strips_site_name_around_pipe_and_decodes_entities() {
+        assert_eq!(extract_title(Judge::Eolymp, &fixture("problem_title_pipe.html")), Ok("A + B & C".to_string()));
+    }
+
+    #[test]
+    fn keeps_problem_names_that_mention_errors() {
+        assert_eq!(extract_title(Judge::Eolymp, &fixture("problem_error_in_name.html")), Ok("Error correction".to_string()));
+    }
+
+    #[test]
+    fn detects_not_found_pages() {
+        assert!(matches!(extract_title(Judge::Eolymp, &fixture("not_found.html")), Err(FetchError::ErrorPage(_))));
+        assert!(matches!(extract_title(Judge::Eolymp, &fixture("not_found_uk.html")), Err(FetchError::ErrorPage(_))));
+    }
+
+    #[test]
+    fn detects_server_error_pages() {
+        assert!(matches!(extract_title(Judge::Eolymp, &fixture("server_error.html")), Err(FetchError::ErrorPage(_))));
+    }
+
+    #[test]
+    fn detects_login_pages() {
+        assert_eq!(extract_title(Judge::Eolymp, &fixture("login.html")), Err(FetchError::LoginRequired));
+    }
+
+    #[test]
+    fn reports_missing_title() {
+        assert_eq!(extract_title(Judge::Eolymp, &fixture("empty.html")), Err(FetchError::NoTitle));
+    }
+
+    #[test]
+    fn strip_site_name_leaves_plain_titles_alone() {
+        assert_eq!(strip_site_name("Graphs: BFS"), "Graphs: BFS");
+        assert_eq!(strip_site_name("Eolymp: BFS"), "BFS");
+        assert_eq!(strip_site_name("EOLYMP"), "");
+    }
+
+    #[test]
+    fn extracts_codeforces_title() {
+        assert_eq!(
+            extract_title(Judge::Codeforces, &fixture("codeforces_problem.html")),
+            Ok("Watermelon".to_string())
+        );
+    }
+
+    #[test]
+    fn extracts_codeforces_tags_and_rating() {
+        let meta = extract_meta(Judge::Codeforces, &fixture("codeforces_problem.html"));
+        assert_eq!(meta.tags, vec!["brute force".to_string(), "math".to_string()]);
+        assert_eq!(meta.difficulty, Some(800));
+        assert_eq!(extract_meta(Judge::Eolymp, &fixture("problem_og_title.html")), ProblemMeta::default());
+    }
+
+    #[test]
+    fn codeforces_redirect_to_problemset_has_no_title() {
+        assert_eq!(
+            extract_title(Judge::Codeforces, &fixture("codeforces_missing.html")),
+            Err(FetchError::NoTitle)
+        );
+    }
+
+    #[test]
+    fn extracts_atcoder_title_without_editorial_link() {
+        assert_eq!(extract_title(Judge::AtCoder, &fixture("atcoder_problem.html")), Ok("Product".to_string()));
+    }
+
+    #[test]
+    fn extracts_timus_title() {
+        assert_eq!(extract_title(Judge::Timus, &fixture("timus_problem.html")), Ok("A+B Problem".to_string()));
+    }
+
+    #[test]
+    fn status_codes_need_a_separator() {
+        assert!(is_status_code("404"));
+        assert!(is_status_code("500: internal server error"));
+        assert!(!is_status_code("500 miles"));
+    }
+}
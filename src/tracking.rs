@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use chrono::{Days, Local, NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::judge::Judge;
+
+/// Скільки останніх днів показувати в підсумках часу.
+pub const DAYS_SHOWN: i64 = 7;
+
+/// Таймер задачі; `session` порожнє, поки таймер на паузі.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tracker {
+    pub judge: Judge,
+    pub problem_id: String,
+    pub session: Option<(i32, NaiveDateTime)>,
+}
+
+impl Tracker {
+    pub fn tracks(&self, judge: Judge, problem_id: &str) -> bool {
+        self.judge == judge && self.problem_id == problem_id
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.session.is_some()
+    }
+
+    /// Тривалість поточної незавершеної сесії.
+    pub fn running_seconds(&self, now: NaiveDateTime) -> i64 {
+        self.session.map_or(0, |(_, started_at)| (now - started_at).num_seconds().max(0))
+    }
+}
+
+/// Час сесій за кожен з останніх `DAYS_SHOWN` днів до `today` включно, від найновішого;
+/// дні без роботи пропущено. Сесія, що перетнула північ, ділиться між днями.
+pub fn time_per_day(sessions: &[(NaiveDateTime, NaiveDateTime)], today: NaiveDate) -> Vec<(NaiveDate, i64)> {
+    let first = today - Days::new(DAYS_SHOWN as u64 - 1);
+    let mut totals = BTreeMap::<NaiveDate, i64>::new();
+
+    for &(started_at, ended_at) in sessions {
+        let mut from = started_at;
+        while from < ended_at {
+            let midnight = (from.date() + Days::new(1)).and_time(NaiveTime::MIN);
+            let to = ended_at.min(midnight);
+            if (first..=today).contains(&from.date()) {
+                *totals.entry(from.date()).or_default() += (to - from).num_seconds();
+            }
+            from = to;
+        }
+    }
+
+    totals.into_iter().rev().collect()
+}
+
+/// Сесії зберігаються в UTC, а дні рахуються за місцевим часом.
+pub fn local_time(utc: NaiveDateTime) -> NaiveDateTime {
+    utc.and_utc().with_timezone(&Local).naive_local()
+}
+
+pub fn format_seconds(seconds: i64) -> String {
+    let seconds = seconds.max(0);
+    format!("{}:{:02}:{:02}", seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate};
+
+    use super::*;
+
+    #[test]
+    fn paused_tracker_adds_no_time() {
+        let started_at = NaiveDate::from_ymd_opt(2025, 6, 1).unwrap().and_hms_opt(9, 0, 0).unwrap();
+        let mut tracker = Tracker { judge: Judge::Timus, problem_id: "1000".to_string(), session: Some((1, started_at)) };
+
+        assert!(tracker.tracks(Judge::Timus, "1000"));
+        assert!(!tracker.tracks(Judge::Eolymp, "1000"));
+        assert_eq!(tracker.running_seconds(started_at + Duration::seconds(90)), 90);
+        assert_eq!(tracker.running_seconds(started_at - Duration::seconds(5)), 0);
+
+        tracker.session = None;
+        assert!(!tracker.is_running());
+        assert_eq!(tracker.running_seconds(started_at + Duration::hours(1)), 0);
+    }
+
+    #[test]
+    fn session_across_midnight_is_split_between_days() {
+        let day = |d| NaiveDate::from_ymd_opt(2025, 6, d).unwrap();
+        let sessions = vec![
+            (day(1).and_hms_opt(23, 30, 0).unwrap(), day(2).and_hms_opt(0, 45, 0).unwrap()),
+            (day(2).and_hms_opt(10, 0, 0).unwrap(), day(2).and_hms_opt(10, 10, 0).unwrap()),
+            // Тиждень тому — за межами підсумку
+            (day(1).and_hms_opt(9, 0, 0).unwrap() - Duration::days(7), day(1).and_hms_opt(10, 0, 0).unwrap() - Duration::days(7)),
+        ];
+
+        assert_eq!(time_per_day(&sessions, day(2)), vec![(day(2), 55 * 60), (day(1), 30 * 60)]);
+
+        // Незавершена з учора сесія до сьогоднішнього полудня
+        let running = [(day(1).and_hms_opt(22, 0, 0).unwrap(), day(2).and_hms_opt(12, 0, 0).unwrap())];
+        assert_eq!(time_per_day(&running, day(2)), vec![(day(2), 12 * 3600), (day(1), 2 * 3600)]);
+    }
+
+    #[test]
+    fn seconds_are_formatted_as_hours_minutes_seconds() {
+        assert_eq!(format_seconds(0), "0:00:00");
+        assert_eq!(format_seconds(3 * 3600 + 7 * 60 + 5), "3:07:05");
+        assert_eq!(format_seconds(-10), "0:00:00");
+    }
+}
@@ -0,0 +1,327 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::future::Future;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cache;
+use crate::fetcher::FetchError;
+
+/// Змінна середовища, що вмикає запис або відтворення відповідей: `record:<dir>` чи `replay:<dir>`.
+pub const TRANSPORT_ENV: &str = "EOLYMP_TRANSPORT";
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Method {
+    Get,
+    Head,
+    Post,
+}
+
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+}
+
+impl Request {
+    pub fn new(method: Method, url: &str) -> Self {
+        Self {
+            method,
+            url: url.to_string(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_lowercase(), value.to_string()));
+        self
+    }
+
+    pub fn body(mut self, body: String) -> Self {
+        self.body = Some(body);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Response {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(default)]
+    pub body: String,
+}
+
+impl Response {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(String::as_str)
+    }
+}
+
+/// Спосіб доставки HTTP-запитів; підміняється, щоб перевіряти код без мережі.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, FetchError>>;
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum TransportMode {
+    #[default]
+    Live,
+    Record(PathBuf),
+    Replay(PathBuf),
+}
+
+impl TransportMode {
+    pub fn from_env() -> Self {
+        let Ok(value) = env::var(TRANSPORT_ENV) else {
+            return TransportMode::Live;
+        };
+
+        match value.split_once(':') {
+            Some(("record", dir)) => TransportMode::Record(PathBuf::from(dir)),
+            Some(("replay", dir)) => TransportMode::Replay(PathBuf::from(dir)),
+            _ => TransportMode::Live,
+        }
+    }
+
+    /// Обгортає живий транспорт відповідно до режиму.
+    pub fn wrap(&self, live: Arc<dyn Transport>) -> Arc<dyn Transport> {
+        match self {
+            TransportMode::Live => live,
+            TransportMode::Record(dir) => Arc::new(RecordTransport::new(live, dir.clone())),
+            TransportMode::Replay(dir) => Arc::new(ReplayTransport::new(dir.clone())),
+        }
+    }
+}
+
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, FetchError>> {
+        Box::pin(async move {
+            let method = match request.method {
+                Method::Get => reqwest::Method::GET,
+                Method::Head => reqwest::Method::HEAD,
+                Method::Post => reqwest::Method::POST,
+            };
+
+            let mut builder = self.client.request(method, &request.url);
+            for (name, value) in &request.headers {
+                builder = builder.header(name, value);
+            }
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status().as_u16();
+            let headers = response.headers()
+                .iter()
+                .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+                .collect();
+
+            Ok(Response {
+                status,
+                headers,
+                body: response.text().await?,
+            })
+        })
+    }
+}
+
+/// Записана пара запит–відповідь; один файл на запит.
+#[derive(Debug, Serialize, Deserialize)]
+struct Recording {
+    method: Method,
+    url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    request_body: Option<String>,
+    response: Response,
+}
+
+// Однакові запити мають потрапляти в той самий файл між запусками
+fn recording_path(dir: &std::path::Path, request: &Request) -> PathBuf {
+    let key = format!(
+        "{:?} {} {}",
+        request.method,
+        request.url,
+        request.body.as_deref().unwrap_or_default()
+    );
+    dir.join(format!("{:016x}.toml", cache::fnv1a(&key)))
+}
+
+/// Віддає відповіді, раніше записані `RecordTransport`, і ніколи не ходить у мережу.
+pub struct ReplayTransport {
+    dir: PathBuf,
+}
+
+impl ReplayTransport {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, FetchError>> {
+        Box::pin(async move {
+            // Відсутній чи зіпсований запис — помилка налаштування тестів, а не втрата зв'язку,
+            // тож її не повторюють і через неї застосунок не переходить в офлайн
+            let path = recording_path(&self.dir, &request);
+            let text = fs::read_to_string(&path).map_err(|_| {
+                FetchError::Replay(format!("no recorded response for {:?} {}", request.method, request.url))
+            })?;
+
+            let recording = toml::from_str::<Recording>(&text)
+                .map_err(|e| FetchError::Replay(format!("broken recording {}: {}", path.display(), e)))?;
+            Ok(recording.response)
+        })
+    }
+}
+
+/// Пропускає запити далі та зберігає кожну відповідь для подальшого відтворення.
+pub struct RecordTransport {
+    inner: Arc<dyn Transport>,
+    dir: PathBuf,
+}
+
+impl RecordTransport {
+    pub fn new(inner: Arc<dyn Transport>, dir: PathBuf) -> Self {
+        Self { inner, dir }
+    }
+}
+
+impl Transport for RecordTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, FetchError>> {
+        Box::pin(async move {
+            let path = recording_path(&self.dir, &request);
+            let method = request.method;
+            let url = request.url.clone();
+            let request_body = request.body.clone();
+
+            let response = self.inner.send(request).await?;
+
+            let recording = Recording { method, url, request_body, response };
+            // Відповідь без запису зробила б набір для відтворення неповним непомітно,
+            // тож збій запису стає помилкою запиту й потрапляє до журналу помилок
+            fs::create_dir_all(&self.dir)
+                .and_then(|_| toml::to_string(&recording).map_err(std::io::Error::other))
+                .and_then(|text| fs::write(&path, text))
+                .map_err(|e| FetchError::Record(format!("{}: {}", path.display(), e)))?;
+
+            Ok(recording.response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    struct StaticTransport {
+        calls: AtomicUsize,
+    }
+
+    impl Transport for StaticTransport {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<Response, FetchError>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                Ok(Response {
+                    status: 200,
+                    headers: BTreeMap::from([("etag".to_string(), "\"v1\"".to_string())]),
+                    body: format!("<title>{}</title>", request.url),
+                })
+            })
+        }
+    }
+
+    fn block_on<T>(future: impl Future<Output = T>) -> T {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("eolymp-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn recorded_responses_are_replayed() {
+        let dir = temp_dir("record");
+        let live = Arc::new(StaticTransport { calls: AtomicUsize::new(0) });
+        let recorder = RecordTransport::new(live.clone(), dir.clone());
+        let request = Request::new(Method::Get, "https://eolymp.com/en/problems/1");
+
+        let recorded = block_on(recorder.send(request.clone())).unwrap();
+        assert_eq!(live.calls.load(Ordering::SeqCst), 1);
+
+        let replayed = block_on(ReplayTransport::new(dir.clone()).send(request)).unwrap();
+        assert_eq!(replayed.status, 200);
+        assert_eq!(replayed.body, recorded.body);
+        assert_eq!(replayed.header("ETag"), Some("\"v1\""));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn replay_distinguishes_post_bodies() {
+        let dir = temp_dir("bodies");
+        let recorder = RecordTransport::new(Arc::new(StaticTransport { calls: AtomicUsize::new(0) }), dir.clone());
+        let request = Request::new(Method::Post, "https://api.eolymp.com/graphql").body("{\"id\":1}".to_string());
+        block_on(recorder.send(request)).unwrap();
+
+        let other = Request::new(Method::Post, "https://api.eolymp.com/graphql").body("{\"id\":2}".to_string());
+        let missing = block_on(ReplayTransport::new(dir.clone()).send(other)).unwrap_err();
+        assert!(matches!(missing, FetchError::Replay(_)));
+        // Без запису не варто ні повторювати запит, ні вважати, що зник зв'язок
+        assert!(!missing.is_transient());
+        assert!(!missing.is_offline());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_recording_is_an_error() {
+        // Каталог для записів неможливо створити: на його місці звичайний файл
+        let dir = temp_dir("unwritable");
+        fs::write(&dir, "").unwrap();
+        let recorder = RecordTransport::new(Arc::new(StaticTransport { calls: AtomicUsize::new(0) }), dir.clone());
+
+        let result = block_on(recorder.send(Request::new(Method::Get, "https://eolymp.com/en/problems/1")));
+        assert!(matches!(result, Err(FetchError::Record(_))));
+
+        fs::remove_file(dir).unwrap();
+    }
+
+    #[test]
+    fn replays_checked_in_recordings() {
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/recorded");
+        let request = Request::new(Method::Get, "https://eolymp.com/en/problems/1");
+
+        let response = block_on(ReplayTransport::new(dir).send(request)).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(crate::title::extract_title(crate::judge::Judge::Eolymp, &response.body), Ok("A + B".to_string()));
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+use rand::Rng;
+
+use crate::db::HistoryEntry;
+
+pub const MAX_WEIGHT: f32 = 5.0;
+pub const DEFAULT_TARGET: u32 = 1500;
+
+// Пропущена задача втрачає половину ваги щотижня; рейтинг оцінюється в межах ±300 від цілі
+const SKIP_HALF_LIFE_DAYS: f64 = 7.0;
+const DIFFICULTY_SPREAD: f64 = 300.0;
+
+/// Ваги стратегій вибору: скільки уваги приділяти кожній із них відносно інших.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Weights {
+    pub enabled: bool,
+    pub fresh: f32,
+    pub tags: f32,
+    pub skipped: f32,
+    pub difficulty: f32,
+    pub target: u32,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fresh: 1.0,
+            tags: 1.0,
+            skipped: 1.0,
+            difficulty: 1.0,
+            target: DEFAULT_TARGET,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Choice {
+    /// Нова випадкова задача, як без зважування.
+    Fresh,
+    /// Задача з історії за індексом.
+    Known(usize),
+}
+
+/// Ймовірності кожного варіанта; задачі з історії відсортовані за спаданням.
+#[derive(Debug, Clone, Default)]
+pub struct Distribution {
+    pub fresh: f64,
+    pub known: Vec<(usize, f64)>,
+}
+
+impl Distribution {
+    pub fn pick(&self, rng: &mut impl Rng) -> Choice {
+        let mut roll = rng.random::<f64>() - self.fresh;
+        for &(idx, probability) in &self.known {
+            if roll < probability {
+                return Choice::Known(idx);
+            }
+            roll -= probability;
+        }
+        Choice::Fresh
+    }
+}
+
+/// Спершу за вагами обирається стратегія, потім задача — пропорційно її оцінці в цій стратегії.
+/// Стратегії без жодної придатної задачі не враховуються. Уже відкриті задачі не пропонуються.
+pub fn distribution(weights: &Weights, history: &[HistoryEntry], now: NaiveDateTime) -> Distribution {
+    let practised = practised_tags(history);
+    let candidates = history
+        .iter()
+        .enumerate()
+        .filter(|(_, entry)| entry.opened == 0)
+        .collect::<Vec<_>>();
+
+    let score_all = |score: &dyn Fn(&HistoryEntry) -> f64| {
+        candidates.iter().map(|(idx, entry)| (*idx, score(entry))).collect::<Vec<_>>()
+    };
+    let strategies = [
+        (weights.tags, score_all(&|entry| tag_score(entry, &practised))),
+        (weights.skipped, score_all(&|entry| skip_score(entry, now))),
+        (weights.difficulty, score_all(&|entry| difficulty_score(entry, weights.target))),
+    ];
+
+    let mut active = Vec::new();
+    for (weight, scores) in strategies {
+        let total = scores.iter().map(|(_, score)| score).sum::<f64>();
+        if weight > 0.0 && total > 0.0 {
+            active.push((weight as f64, scores, total));
+        }
+    }
+
+    let total_weight = weights.fresh.max(0.0) as f64 + active.iter().map(|(weight, _, _)| weight).sum::<f64>();
+    if total_weight <= 0.0 {
+        return Distribution { fresh: 1.0, known: Vec::new() };
+    }
+
+    let mut known = HashMap::<usize, f64>::new();
+    for (weight, scores, total) in active {
+        for (idx, score) in scores {
+            *known.entry(idx).or_default() += weight / total_weight * score / total;
+        }
+    }
+
+    let mut known = known.into_iter().filter(|(_, p)| *p > 0.0).collect::<Vec<_>>();
+    known.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    Distribution {
+        fresh: weights.fresh.max(0.0) as f64 / total_weight,
+        known,
+    }
+}
+
+// Скільки відкритих задач має кожну тему
+fn practised_tags(history: &[HistoryEntry]) -> HashMap<&str, u32> {
+    let mut counts = HashMap::new();
+    for entry in history.iter().filter(|entry| entry.opened > 0) {
+        for tag in entry.tags() {
+            *counts.entry(tag).or_default() += 1;
+        }
+    }
+    counts
+}
+
+fn tag_score(entry: &HistoryEntry, practised: &HashMap<&str, u32>) -> f64 {
+    let scores = entry.tags()
+        .map(|tag| 1.0 / (1.0 + practised.get(tag).copied().unwrap_or_default() as f64))
+        .collect::<Vec<_>>();
+
+    if scores.is_empty() {
+        return 0.0;
+    }
+    scores.iter().sum::<f64>() / scores.len() as f64
+}
+
+fn skip_score(entry: &HistoryEntry, now: NaiveDateTime) -> f64 {
+    if entry.shown == 0 {
+        return 0.0;
+    }
+    let days = (now - entry.last_shown_at).num_minutes().max(0) as f64 / (24.0 * 60.0);
+    0.5f64.powf(days / SKIP_HALF_LIFE_DAYS)
+}
+
+fn difficulty_score(entry: &HistoryEntry, target: u32) -> f64 {
+    entry.difficulty.map_or(0.0, |difficulty| {
+        let distance = (difficulty as f64 - target as f64) / DIFFICULTY_SPREAD;
+        (-distance * distance).exp()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, NaiveDate};
+
+    use super::*;
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 5, 1).unwrap().and_hms_opt(12, 0, 0).unwrap()
+    }
+
+    fn entry(id: &str, tags: &str, difficulty: Option<i32>, opened: i32, days_ago: i64) -> HistoryEntry {
+        HistoryEntry {
+            judge: "codeforces".to_string(),
+            problem_id: id.to_string(),
+            name: String::new(),
+            tags: tags.to_string(),
+            difficulty,
+            shown: 1,
+            opened,
+            last_shown_at: now() - Duration::days(days_ago),
+        }
+    }
+
+    fn probability(distribution: &Distribution, idx: usize) -> f64 {
+        distribution.known.iter().find(|(i, _)| *i == idx).map_or(0.0, |(_, p)| *p)
+    }
+
+    #[test]
+    fn probabilities_sum_to_one() {
+        let history = vec![
+            entry("1A", "math", Some(800), 0, 1),
+            entry("2B", "graphs", Some(1600), 0, 10),
+            entry("3C", "math", None, 2, 0),
+        ];
+        let distribution = distribution(&Weights::default(), &history, now());
+
+        let total = distribution.fresh + distribution.known.iter().map(|(_, p)| p).sum::<f64>();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert_eq!(probability(&distribution, 2), 0.0);
+    }
+
+    #[test]
+    fn each_strategy_prefers_its_candidates() {
+        let history = vec![
+            entry("1A", "math", Some(800), 0, 30),
+            entry("2B", "graphs", Some(1500), 0, 0),
+            entry("3C", "math", None, 1, 0),
+        ];
+        let only = |tags, skipped, difficulty| Weights { fresh: 0.0, tags, skipped, difficulty, ..Weights::default() };
+
+        let by_tags = distribution(&only(1.0, 0.0, 0.0), &history, now());
+        assert!(probability(&by_tags, 1) > probability(&by_tags, 0));
+
+        let by_skips = distribution(&only(0.0, 1.0, 0.0), &history, now());
+        assert!(probability(&by_skips, 1) > probability(&by_skips, 0));
+
+        let by_difficulty = distribution(&only(0.0, 0.0, 1.0), &history, now());
+        assert!(probability(&by_difficulty, 1) > 0.9);
+    }
+
+    #[test]
+    fn zero_weights_fall_back_to_fresh() {
+        let history = vec![entry("1A", "math", Some(1500), 0, 1)];
+        let zero = Weights { fresh: 0.0, tags: 0.0, skipped: 0.0, difficulty: 0.0, ..Weights::default() };
+
+        let fallback = distribution(&zero, &history, now());
+        assert_eq!(fallback.fresh, 1.0);
+        assert!(fallback.known.is_empty());
+        assert_eq!(fallback.pick(&mut rand::rng()), Choice::Fresh);
+
+        // Від'ємна вага нової задачі не робить імовірності від'ємними
+        let negative = Weights { fresh: -1.0, ..zero };
+        assert_eq!(distribution(&negative, &history, now()).fresh, 1.0);
+    }
+
+    #[test]
+    fn strategy_without_candidates_is_ignored() {
+        // Тем і складності немає, тож працює лише стратегія пропущених задач
+        let history = vec![entry("1A", "", None, 0, 7)];
+        let weights = Weights { fresh: 1.0, tags: MAX_WEIGHT, skipped: 1.0, difficulty: MAX_WEIGHT, ..Weights::default() };
+
+        let distribution = distribution(&weights, &history, now());
+        assert_eq!(distribution.fresh, 0.5);
+        assert_eq!(probability(&distribution, 0), 0.5);
+    }
+
+    #[test]
+    fn empty_history_always_picks_fresh() {
+        let distribution = distribution(&Weights::default(), &[], now());
+        assert_eq!(distribution.fresh, 1.0);
+        assert_eq!(distribution.pick(&mut rand::rng()), Choice::Fresh);
+    }
+}